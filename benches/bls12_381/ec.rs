@@ -4,7 +4,7 @@ mod g1 {
 
     use blstrs::*;
     use ff::Field;
-    use group::Group;
+    use group::{Curve, Group};
 
     #[bench]
     fn bench_g1_mul_assign(b: &mut ::test::Bencher) {
@@ -28,6 +28,72 @@ mod g1 {
         });
     }
 
+    #[bench]
+    fn bench_g1_mul_by_generator(b: &mut ::test::Bencher) {
+        const SAMPLES: usize = 1000;
+
+        let mut rng = XorShiftRng::from_seed([
+            0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06,
+            0xbc, 0xe5,
+        ]);
+
+        let v: Vec<Scalar> = (0..SAMPLES).map(|_| Scalar::random(&mut rng)).collect();
+        G1Projective::mul_by_generator(&v[0]);
+
+        let mut count = 0;
+        b.iter(|| {
+            let tmp = G1Projective::mul_by_generator(&v[count]);
+            count = (count + 1) % SAMPLES;
+            tmp
+        });
+    }
+
+    #[bench]
+    fn bench_g1_to_affine(b: &mut ::test::Bencher) {
+        const SIZE: usize = 1 << 12;
+
+        let mut rng = XorShiftRng::from_seed([
+            0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06,
+            0xbc, 0xe5,
+        ]);
+
+        let v: Vec<G1Projective> = (0..SIZE).map(|_| G1Projective::random(&mut rng)).collect();
+
+        b.iter(|| v.iter().map(|p| p.to_affine()).collect::<Vec<_>>());
+    }
+
+    #[bench]
+    fn bench_g1_batch_normalize(b: &mut ::test::Bencher) {
+        const SIZE: usize = 1 << 12;
+
+        let mut rng = XorShiftRng::from_seed([
+            0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06,
+            0xbc, 0xe5,
+        ]);
+
+        let v: Vec<G1Projective> = (0..SIZE).map(|_| G1Projective::random(&mut rng)).collect();
+        let mut affine = vec![G1Affine::default(); SIZE];
+
+        b.iter(|| G1Projective::batch_normalize(&v, &mut affine));
+    }
+
+    #[cfg(feature = "parallel")]
+    #[bench]
+    fn bench_g1_batch_from_compressed(b: &mut ::test::Bencher) {
+        const SIZE: usize = 1 << 8;
+
+        let mut rng = XorShiftRng::from_seed([
+            0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06,
+            0xbc, 0xe5,
+        ]);
+
+        let v: Vec<_> = (0..SIZE)
+            .map(|_| G1Projective::random(&mut rng).to_affine().to_compressed())
+            .collect();
+
+        b.iter(|| G1Affine::batch_from_compressed(&v));
+    }
+
     #[bench]
     fn bench_g1_add_assign(b: &mut ::test::Bencher) {
         const SAMPLES: usize = 1000;
@@ -89,7 +155,7 @@ mod g2 {
 
     use blstrs::*;
     use ff::Field;
-    use group::Group;
+    use group::{Curve, Group};
 
     #[bench]
     fn bench_g2_mul_assign(b: &mut ::test::Bencher) {
@@ -113,6 +179,72 @@ mod g2 {
         });
     }
 
+    #[bench]
+    fn bench_g2_mul_by_generator(b: &mut ::test::Bencher) {
+        const SAMPLES: usize = 1000;
+
+        let mut rng = XorShiftRng::from_seed([
+            0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06,
+            0xbc, 0xe5,
+        ]);
+
+        let v: Vec<Scalar> = (0..SAMPLES).map(|_| Scalar::random(&mut rng)).collect();
+        G2Projective::mul_by_generator(&v[0]);
+
+        let mut count = 0;
+        b.iter(|| {
+            let tmp = G2Projective::mul_by_generator(&v[count]);
+            count = (count + 1) % SAMPLES;
+            tmp
+        });
+    }
+
+    #[bench]
+    fn bench_g2_to_affine(b: &mut ::test::Bencher) {
+        const SIZE: usize = 1 << 12;
+
+        let mut rng = XorShiftRng::from_seed([
+            0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06,
+            0xbc, 0xe5,
+        ]);
+
+        let v: Vec<G2Projective> = (0..SIZE).map(|_| G2Projective::random(&mut rng)).collect();
+
+        b.iter(|| v.iter().map(|p| p.to_affine()).collect::<Vec<_>>());
+    }
+
+    #[bench]
+    fn bench_g2_batch_normalize(b: &mut ::test::Bencher) {
+        const SIZE: usize = 1 << 12;
+
+        let mut rng = XorShiftRng::from_seed([
+            0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06,
+            0xbc, 0xe5,
+        ]);
+
+        let v: Vec<G2Projective> = (0..SIZE).map(|_| G2Projective::random(&mut rng)).collect();
+        let mut affine = vec![G2Affine::default(); SIZE];
+
+        b.iter(|| G2Projective::batch_normalize(&v, &mut affine));
+    }
+
+    #[cfg(feature = "parallel")]
+    #[bench]
+    fn bench_g2_batch_from_compressed(b: &mut ::test::Bencher) {
+        const SIZE: usize = 1 << 8;
+
+        let mut rng = XorShiftRng::from_seed([
+            0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06,
+            0xbc, 0xe5,
+        ]);
+
+        let v: Vec<_> = (0..SIZE)
+            .map(|_| G2Projective::random(&mut rng).to_affine().to_compressed())
+            .collect();
+
+        b.iter(|| G2Affine::batch_from_compressed(&v));
+    }
+
     #[bench]
     fn bench_g2_add_assign(b: &mut ::test::Bencher) {
         const SAMPLES: usize = 1000;
@@ -1,9 +1,6 @@
 mod ec;
-#[cfg(feature = "__private_bench")]
 mod fp;
-#[cfg(feature = "__private_bench")]
 mod fp12;
-#[cfg(feature = "__private_bench")]
 mod fp2;
 mod scalar;
 
@@ -151,6 +148,94 @@ fn bench_g1_multi_exp(b: &mut ::test::Bencher) {
     b.iter(|| G1Projective::multi_exp(points.as_slice(), scalars.as_slice()));
 }
 
+#[bench]
+fn bench_g1_multi_exp_u64(b: &mut ::test::Bencher) {
+    use rand_core::RngCore;
+    const SIZE: usize = 256;
+
+    let mut rng = XorShiftRng::from_seed([
+        0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06, 0xbc,
+        0xe5,
+    ]);
+
+    let points: Vec<G1Projective> = (0..SIZE).map(|_| G1Projective::random(&mut rng)).collect();
+    let scalars: Vec<u64> = (0..SIZE).map(|_| rng.next_u64()).collect();
+
+    b.iter(|| G1Projective::multi_exp_u64(points.as_slice(), scalars.as_slice()));
+}
+
+#[bench]
+fn bench_g1_multi_exp_bits(b: &mut ::test::Bencher) {
+    use rand_core::RngCore;
+    const SIZE: usize = 256;
+
+    let mut rng = XorShiftRng::from_seed([
+        0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06, 0xbc,
+        0xe5,
+    ]);
+
+    let points: Vec<G1Projective> = (0..SIZE).map(|_| G1Projective::random(&mut rng)).collect();
+    let bits: Vec<bool> = (0..SIZE).map(|_| rng.next_u32() & 1 == 1).collect();
+
+    b.iter(|| G1Projective::multi_exp_bits(points.as_slice(), bits.as_slice()));
+}
+
+#[bench]
+fn bench_g1_affine_multi_exp(b: &mut ::test::Bencher) {
+    use ff::Field;
+    const SIZE: usize = 256;
+
+    let mut rng = XorShiftRng::from_seed([
+        0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06, 0xbc,
+        0xe5,
+    ]);
+
+    let points: Vec<G1Affine> = (0..SIZE)
+        .map(|_| G1Projective::random(&mut rng).to_affine())
+        .collect();
+    let scalars: Vec<Scalar> = (0..SIZE).map(|_| Scalar::random(&mut rng)).collect();
+
+    b.iter(|| G1Affine::multi_exp(points.as_slice(), scalars.as_slice()));
+}
+
+#[bench]
+fn bench_g1_batch_multi_exp(b: &mut ::test::Bencher) {
+    use ff::Field;
+    const SIZE: usize = 256;
+    const BATCH: usize = 16;
+
+    let mut rng = XorShiftRng::from_seed([
+        0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06, 0xbc,
+        0xe5,
+    ]);
+
+    let points: Vec<G1Projective> = (0..SIZE).map(|_| G1Projective::random(&mut rng)).collect();
+    let scalars: Vec<Vec<Scalar>> = (0..BATCH)
+        .map(|_| (0..SIZE).map(|_| Scalar::random(&mut rng)).collect())
+        .collect();
+    let scalars: Vec<&[Scalar]> = scalars.iter().map(|s| s.as_slice()).collect();
+
+    b.iter(|| G1Projective::batch_multi_exp(points.as_slice(), scalars.as_slice()));
+}
+
+#[bench]
+fn bench_g1_msm_bases_precomputed(b: &mut ::test::Bencher) {
+    use ff::Field;
+    const SIZE: usize = 256;
+
+    let mut rng = XorShiftRng::from_seed([
+        0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06, 0xbc,
+        0xe5,
+    ]);
+
+    let points: Vec<G1Projective> = (0..SIZE).map(|_| G1Projective::random(&mut rng)).collect();
+    let scalars: Vec<Scalar> = (0..SIZE).map(|_| Scalar::random(&mut rng)).collect();
+    let mut bases = G1MsmBases::from_projective(&points);
+    bases.precompute(8);
+
+    b.iter(|| bases.msm(scalars.as_slice()));
+}
+
 #[bench]
 fn bench_g2_multi_exp_naive(b: &mut ::test::Bencher) {
     use ff::Field;
@@ -187,3 +272,186 @@ fn bench_g2_multi_exp(b: &mut ::test::Bencher) {
 
     b.iter(|| G2Projective::multi_exp(points.as_slice(), scalars.as_slice()));
 }
+
+#[bench]
+fn bench_g2_affine_multi_exp(b: &mut ::test::Bencher) {
+    use ff::Field;
+    const SIZE: usize = 256;
+
+    let mut rng = XorShiftRng::from_seed([
+        0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06, 0xbc,
+        0xe5,
+    ]);
+
+    let points: Vec<G2Affine> = (0..SIZE)
+        .map(|_| G2Projective::random(&mut rng).to_affine())
+        .collect();
+    let scalars: Vec<Scalar> = (0..SIZE).map(|_| Scalar::random(&mut rng)).collect();
+
+    b.iter(|| G2Affine::multi_exp(points.as_slice(), scalars.as_slice()));
+}
+
+#[bench]
+fn bench_gt_mul(b: &mut ::test::Bencher) {
+    use ff::Field;
+
+    let mut rng = XorShiftRng::from_seed([
+        0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06, 0xbc,
+        0xe5,
+    ]);
+
+    let p = Gt::random(&mut rng);
+    let s = Scalar::random(&mut rng);
+
+    b.iter(|| p * s);
+}
+
+#[bench]
+fn bench_gt_mul_vartime(b: &mut ::test::Bencher) {
+    use ff::Field;
+
+    let mut rng = XorShiftRng::from_seed([
+        0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06, 0xbc,
+        0xe5,
+    ]);
+
+    let p = Gt::random(&mut rng);
+    let s = Scalar::random(&mut rng);
+
+    b.iter(|| p.mul_vartime(&s));
+}
+
+#[bench]
+fn bench_gt_multi_exp_naive(b: &mut ::test::Bencher) {
+    use ff::Field;
+    const SIZE: usize = 64;
+
+    let mut rng = XorShiftRng::from_seed([
+        0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06, 0xbc,
+        0xe5,
+    ]);
+
+    let points: Vec<Gt> = (0..SIZE).map(|_| Gt::random(&mut rng)).collect();
+    let scalars: Vec<Scalar> = (0..SIZE).map(|_| Scalar::random(&mut rng)).collect();
+
+    b.iter(|| {
+        let mut acc = points[0] * scalars[0];
+        for i in 1..SIZE {
+            acc += points[i] * scalars[i];
+        }
+    });
+}
+
+#[bench]
+fn bench_gt_multi_exp(b: &mut ::test::Bencher) {
+    use ff::Field;
+    const SIZE: usize = 64;
+
+    let mut rng = XorShiftRng::from_seed([
+        0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06, 0xbc,
+        0xe5,
+    ]);
+
+    let points: Vec<Gt> = (0..SIZE).map(|_| Gt::random(&mut rng)).collect();
+    let scalars: Vec<Scalar> = (0..SIZE).map(|_| Scalar::random(&mut rng)).collect();
+
+    b.iter(|| Gt::multi_exp(points.as_slice(), scalars.as_slice()));
+}
+
+/// Benchmarks `multi_exp` on `2^log_size` points of `G`, converted to `P`.
+#[cfg(feature = "parallel")]
+fn bench_multi_exp_scaling<G, P, R>(
+    b: &mut ::test::Bencher,
+    log_size: usize,
+    multi_exp: fn(&[P], &[Scalar]) -> R,
+) where
+    G: group::prime::PrimeCurve<Scalar = Scalar>,
+    P: From<G::Affine>,
+{
+    use ff::Field;
+    use group::prime::PrimeCurveAffine;
+
+    let mut rng = XorShiftRng::from_seed([
+        0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06, 0xbc,
+        0xe5,
+    ]);
+
+    // Hashing 2^20 random points is slow, so consecutive multiples are used instead.
+    let mut p = G::random(&mut rng);
+    let projective: Vec<G> = (0..1 << log_size)
+        .map(|_| {
+            p += G::generator();
+            p
+        })
+        .collect();
+    let mut affine = vec![G::Affine::identity(); projective.len()];
+    G::batch_normalize(&projective, &mut affine);
+    let points: Vec<P> = affine.into_iter().map(P::from).collect();
+    let scalars: Vec<Scalar> = (0..points.len())
+        .map(|_| Scalar::random(&mut rng))
+        .collect();
+
+    b.iter(|| multi_exp(points.as_slice(), scalars.as_slice()));
+}
+
+/// Generates a module of benchmarks of `$points::$multi_exp` on 2^16, 2^18 and 2^20 points.
+#[cfg(feature = "parallel")]
+macro_rules! bench_multi_exp_scaling {
+    ($module:ident, $group:ident, $points:ident, $multi_exp:ident) => {
+        mod $module {
+            use super::*;
+
+            #[bench]
+            fn bench_2_16(b: &mut ::test::Bencher) {
+                bench_multi_exp_scaling::<$group, $points, _>(b, 16, $points::$multi_exp);
+            }
+
+            #[bench]
+            fn bench_2_18(b: &mut ::test::Bencher) {
+                bench_multi_exp_scaling::<$group, $points, _>(b, 18, $points::$multi_exp);
+            }
+
+            #[bench]
+            fn bench_2_20(b: &mut ::test::Bencher) {
+                bench_multi_exp_scaling::<$group, $points, _>(b, 20, $points::$multi_exp);
+            }
+        }
+    };
+}
+
+#[cfg(feature = "parallel")]
+bench_multi_exp_scaling!(g1_multi_exp, G1Projective, G1Projective, multi_exp);
+#[cfg(feature = "parallel")]
+bench_multi_exp_scaling!(
+    g1_multi_exp_parallel,
+    G1Projective,
+    G1Projective,
+    multi_exp_parallel
+);
+#[cfg(feature = "parallel")]
+bench_multi_exp_scaling!(g1_affine_multi_exp, G1Projective, G1Affine, multi_exp);
+#[cfg(feature = "parallel")]
+bench_multi_exp_scaling!(
+    g1_affine_multi_exp_parallel,
+    G1Projective,
+    G1Affine,
+    multi_exp_parallel
+);
+#[cfg(feature = "parallel")]
+bench_multi_exp_scaling!(g2_multi_exp, G2Projective, G2Projective, multi_exp);
+#[cfg(feature = "parallel")]
+bench_multi_exp_scaling!(
+    g2_multi_exp_parallel,
+    G2Projective,
+    G2Projective,
+    multi_exp_parallel
+);
+#[cfg(feature = "parallel")]
+bench_multi_exp_scaling!(g2_affine_multi_exp, G2Projective, G2Affine, multi_exp);
+#[cfg(feature = "parallel")]
+bench_multi_exp_scaling!(
+    g2_affine_multi_exp_parallel,
+    G2Projective,
+    G2Affine,
+    multi_exp_parallel
+);
@@ -107,6 +107,24 @@ fn bench_scalar_inverse(b: &mut ::test::Bencher) {
     });
 }
 
+#[bench]
+fn bench_scalar_batch_invert(b: &mut ::test::Bencher) {
+    const SAMPLES: usize = 1000;
+
+    let mut rng = XorShiftRng::from_seed([
+        0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06, 0xbc,
+        0xe5,
+    ]);
+
+    let v: Vec<Scalar> = (0..SAMPLES).map(|_| Scalar::random(&mut rng)).collect();
+
+    b.iter(|| {
+        let mut tmp = v.clone();
+        Scalar::batch_invert(&mut tmp);
+        tmp
+    });
+}
+
 #[bench]
 fn bench_scalar_negate(b: &mut ::test::Bencher) {
     const SAMPLES: usize = 1000;
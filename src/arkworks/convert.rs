@@ -0,0 +1,309 @@
+//! Conversions to and from the types of `ark_bls12_381`.
+//!
+//! Both crates store field elements in Montgomery form with the same parameters, so the
+//! conversions copy limbs directly.
+
+use core::convert::TryFrom;
+
+use ark_bls12_381::{Bls12_381, Fq, Fq12, Fq2, Fq6, Fr};
+use ark_ec::{pairing::PairingOutput, AffineRepr};
+use ark_ff::BigInt;
+use blst::*;
+use group::prime::PrimeCurveAffine;
+
+use crate::{
+    fp::Fp, fp12::Fp12, fp2::Fp2, fp6::Fp6, G1Affine, G2Affine, Gt, NotInSubgroupError, Scalar,
+};
+
+impl From<Scalar> for Fr {
+    fn from(s: Scalar) -> Fr {
+        Fr::new_unchecked(BigInt(s.0.l))
+    }
+}
+
+impl From<Fr> for Scalar {
+    fn from(s: Fr) -> Scalar {
+        Scalar(blst_fr { l: (s.0).0 })
+    }
+}
+
+impl From<Fp> for Fq {
+    fn from(f: Fp) -> Fq {
+        Fq::new_unchecked(BigInt(f.0.l))
+    }
+}
+
+impl From<Fq> for Fp {
+    fn from(f: Fq) -> Fp {
+        Fp(blst_fp { l: (f.0).0 })
+    }
+}
+
+impl From<Fp2> for Fq2 {
+    fn from(f: Fp2) -> Fq2 {
+        Fq2::new(f.c0().into(), f.c1().into())
+    }
+}
+
+impl From<Fq2> for Fp2 {
+    fn from(f: Fq2) -> Fp2 {
+        Fp2::new(f.c0.into(), f.c1.into())
+    }
+}
+
+impl From<Fp6> for Fq6 {
+    fn from(f: Fp6) -> Fq6 {
+        Fq6::new(f.c0().into(), f.c1().into(), f.c2().into())
+    }
+}
+
+impl From<Fq6> for Fp6 {
+    fn from(f: Fq6) -> Fp6 {
+        Fp6::new(f.c0.into(), f.c1.into(), f.c2.into())
+    }
+}
+
+impl From<Fp12> for Fq12 {
+    fn from(f: Fp12) -> Fq12 {
+        Fq12::new(f.c0().into(), f.c1().into())
+    }
+}
+
+impl From<Fq12> for Fp12 {
+    fn from(f: Fq12) -> Fp12 {
+        Fp12::new(f.c0.into(), f.c1.into())
+    }
+}
+
+impl From<G1Affine> for ark_bls12_381::G1Affine {
+    fn from(p: G1Affine) -> ark_bls12_381::G1Affine {
+        if p.is_identity().into() {
+            ark_bls12_381::G1Affine::identity()
+        } else {
+            ark_bls12_381::G1Affine::new_unchecked(p.x().into(), p.y().into())
+        }
+    }
+}
+
+impl TryFrom<ark_bls12_381::G1Affine> for G1Affine {
+    type Error = NotInSubgroupError;
+
+    /// Fails if `p` is not on the curve or not in the prime order subgroup.
+    fn try_from(p: ark_bls12_381::G1Affine) -> Result<G1Affine, Self::Error> {
+        let (x, y) = match p.xy() {
+            Some((x, y)) => (*x, *y),
+            None => return Ok(G1Affine::identity()),
+        };
+
+        let p = G1Affine::from_raw_unchecked(x.into(), y.into(), false);
+        if (p.is_on_curve() & p.is_torsion_free()).into() {
+            Ok(p)
+        } else {
+            Err(NotInSubgroupError)
+        }
+    }
+}
+
+impl From<G2Affine> for ark_bls12_381::G2Affine {
+    fn from(p: G2Affine) -> ark_bls12_381::G2Affine {
+        if p.is_identity().into() {
+            ark_bls12_381::G2Affine::identity()
+        } else {
+            ark_bls12_381::G2Affine::new_unchecked(p.x().into(), p.y().into())
+        }
+    }
+}
+
+impl TryFrom<ark_bls12_381::G2Affine> for G2Affine {
+    type Error = NotInSubgroupError;
+
+    /// Fails if `p` is not on the curve or not in the prime order subgroup.
+    fn try_from(p: ark_bls12_381::G2Affine) -> Result<G2Affine, Self::Error> {
+        let (x, y) = match p.xy() {
+            Some((x, y)) => (*x, *y),
+            None => return Ok(G2Affine::identity()),
+        };
+
+        let p = G2Affine::from_raw_unchecked(x.into(), y.into(), false);
+        if (p.is_on_curve() & p.is_torsion_free()).into() {
+            Ok(p)
+        } else {
+            Err(NotInSubgroupError)
+        }
+    }
+}
+
+impl From<Gt> for PairingOutput<Bls12_381> {
+    fn from(gt: Gt) -> PairingOutput<Bls12_381> {
+        PairingOutput(gt.0.into())
+    }
+}
+
+impl TryFrom<PairingOutput<Bls12_381>> for Gt {
+    type Error = NotInSubgroupError;
+
+    /// Fails if `gt` is not in the order `r` subgroup of `Fq12`.
+    fn try_from(gt: PairingOutput<Bls12_381>) -> Result<Gt, Self::Error> {
+        let gt = Gt(gt.0.into());
+        if gt.is_in_subgroup() {
+            Ok(gt)
+        } else {
+            Err(NotInSubgroupError)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use ark_ec::{pairing::Pairing, CurveGroup};
+    use ark_ff::{Field, UniformRand};
+    use ark_serialize::CanonicalSerialize;
+    use group::{Curve, Group};
+
+    use ark_std::test_rng;
+
+    use crate::{G1Projective, G2Projective};
+
+    #[test]
+    fn test_scalar_conversions() {
+        let mut rng = test_rng();
+        for _ in 0..100 {
+            let a = <Scalar as ff::Field>::random(&mut rng);
+            let b = <Scalar as ff::Field>::random(&mut rng);
+            let (a_ark, b_ark) = (Fr::from(a), Fr::from(b));
+
+            assert_eq!(Scalar::from(a_ark), a);
+            assert_eq!(Fr::from(a * b), a_ark * b_ark);
+            assert_eq!(Fr::from(a + b), a_ark + b_ark);
+
+            let a_ark = Fr::rand(&mut rng);
+            assert_eq!(Fr::from(Scalar::from(a_ark)), a_ark);
+        }
+    }
+
+    #[test]
+    fn test_fp12_conversions() {
+        let mut rng = test_rng();
+        for _ in 0..100 {
+            let a = <Fp12 as ff::Field>::random(&mut rng);
+            let b = <Fp12 as ff::Field>::random(&mut rng);
+            let (a_ark, b_ark) = (Fq12::from(a), Fq12::from(b));
+
+            assert_eq!(Fp12::from(a_ark), a);
+            assert_eq!(Fq12::from(a * b), a_ark * b_ark);
+            assert_eq!(Fq12::from(a.square()), a_ark.square());
+            let mut a_frob = a;
+            Fp12::frobenius_map(&mut a_frob, 3);
+            assert_eq!(Fq12::from(a_frob), a_ark.frobenius_map(3));
+        }
+    }
+
+    #[test]
+    fn test_g1_conversions() {
+        let mut rng = test_rng();
+        for _ in 0..100 {
+            let p = G1Projective::random(&mut rng);
+            let q = G1Projective::random(&mut rng);
+            let s = <Scalar as ff::Field>::random(&mut rng);
+            let (p_ark, q_ark) = (
+                ark_bls12_381::G1Affine::from(p.to_affine()),
+                ark_bls12_381::G1Affine::from(q.to_affine()),
+            );
+
+            assert_eq!(G1Affine::try_from(p_ark).unwrap(), p.to_affine());
+            assert_eq!(
+                ark_bls12_381::G1Affine::from((p + q).to_affine()),
+                (p_ark + q_ark).into_affine()
+            );
+            assert_eq!(
+                ark_bls12_381::G1Affine::from((p * s).to_affine()),
+                (p_ark * Fr::from(s)).into_affine()
+            );
+
+            let mut bytes = Vec::new();
+            p_ark.serialize_compressed(&mut bytes).unwrap();
+            assert_eq!(&bytes[..], &p.to_affine().to_compressed()[..]);
+        }
+
+        let zero = ark_bls12_381::G1Affine::identity();
+        assert_eq!(ark_bls12_381::G1Affine::from(G1Affine::identity()), zero);
+        assert_eq!(G1Affine::try_from(zero).unwrap(), G1Affine::identity());
+
+        // A point outside of the prime order subgroup.
+        let p = loop {
+            let x = Fq::rand(&mut rng);
+            if let Some(p) = ark_bls12_381::G1Affine::get_point_from_x_unchecked(x, true) {
+                break p;
+            }
+        };
+        assert!(G1Affine::try_from(p).is_err());
+        assert!(G1Affine::try_from(ark_bls12_381::G1Affine::new_unchecked(
+            Fq::from(1u64),
+            Fq::from(1u64)
+        ))
+        .is_err());
+    }
+
+    #[test]
+    fn test_g2_conversions() {
+        let mut rng = test_rng();
+        for _ in 0..100 {
+            let p = G2Projective::random(&mut rng);
+            let q = G2Projective::random(&mut rng);
+            let s = <Scalar as ff::Field>::random(&mut rng);
+            let (p_ark, q_ark) = (
+                ark_bls12_381::G2Affine::from(p.to_affine()),
+                ark_bls12_381::G2Affine::from(q.to_affine()),
+            );
+
+            assert_eq!(G2Affine::try_from(p_ark).unwrap(), p.to_affine());
+            assert_eq!(
+                ark_bls12_381::G2Affine::from((p + q).to_affine()),
+                (p_ark + q_ark).into_affine()
+            );
+            assert_eq!(
+                ark_bls12_381::G2Affine::from((p * s).to_affine()),
+                (p_ark * Fr::from(s)).into_affine()
+            );
+
+            let mut bytes = Vec::new();
+            p_ark.serialize_compressed(&mut bytes).unwrap();
+            assert_eq!(&bytes[..], &p.to_affine().to_compressed()[..]);
+        }
+
+        let zero = ark_bls12_381::G2Affine::identity();
+        assert_eq!(ark_bls12_381::G2Affine::from(G2Affine::identity()), zero);
+        assert_eq!(G2Affine::try_from(zero).unwrap(), G2Affine::identity());
+
+        // A point outside of the prime order subgroup.
+        let p = loop {
+            let x = Fq2::rand(&mut rng);
+            if let Some(p) = ark_bls12_381::G2Affine::get_point_from_x_unchecked(x, true) {
+                break p;
+            }
+        };
+        assert!(G2Affine::try_from(p).is_err());
+    }
+
+    #[test]
+    fn test_gt_conversions() {
+        let mut rng = test_rng();
+        for _ in 0..10 {
+            let p = G1Projective::random(&mut rng).to_affine();
+            let q = G2Projective::random(&mut rng).to_affine();
+
+            let gt = crate::pairing(&p, &q);
+            let gt_ark = Bls12_381::pairing(
+                ark_bls12_381::G1Affine::from(p),
+                ark_bls12_381::G2Affine::from(q),
+            );
+            assert_eq!(PairingOutput::from(gt), gt_ark);
+            assert_eq!(Gt::try_from(gt_ark).unwrap(), gt);
+        }
+
+        let not_in_subgroup = PairingOutput::<Bls12_381>(Fq12::from(2u64));
+        assert!(Gt::try_from(not_in_subgroup).is_err());
+    }
+}
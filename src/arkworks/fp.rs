@@ -0,0 +1,62 @@
+//! `ark_ff` field traits for [`Fp`].
+
+use core::{
+    hash::{Hash, Hasher},
+    iter,
+    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Sub, SubAssign},
+    str::FromStr,
+};
+
+use ark_ff::{BigInt, FftField, Field, LegendreSymbol, One, PrimeField, SqrtPrecomputation, Zero};
+use ark_serialize::{
+    buffer_byte_size, CanonicalDeserialize, CanonicalDeserializeWithFlags, CanonicalSerialize,
+    CanonicalSerializeWithFlags, EmptyFlags, Flags, Read, SerializationError, Valid, Validate,
+    Write,
+};
+use ark_std::rand::{distributions::Standard, prelude::Distribution, Rng};
+use blst::{blst_fp_mul, blst_uint64_from_fp};
+use num_bigint::BigUint;
+use zeroize::Zeroize;
+
+use crate::fp::{Fp, GENERATOR, MODULUS, R, ROOT_OF_UNITY, ZERO};
+
+/// `(p + 1) / 4` in little-endian non-Montgomery form, used for square roots as `p = 3 mod 4`.
+const MODULUS_PLUS_ONE_DIV_FOUR: [u64; 6] = [
+    0xee7f_bfff_ffff_eaab,
+    0x07aa_ffff_ac54_ffff,
+    0xd9cc_34a8_3dac_3d89,
+    0xd91d_d2e1_3ce1_44af,
+    0x92c6_e9ed_90d2_eb35,
+    0x0680_447a_8e5f_f9a6,
+];
+
+impl_ark_prime_field!(
+    Fp,
+    limbs: 6,
+    bytes: 48,
+    modulus_bit_size: 381,
+    two_adicity: 1,
+    sqrt_precomp: Some(SqrtPrecomputation::Case3Mod4 {
+        modulus_plus_one_div_four: &MODULUS_PLUS_ONE_DIV_FOUR,
+    }),
+    mul: blst_fp_mul,
+    to_u64s: blst_uint64_from_fp,
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ark_constants() {
+        assert_eq!(GENERATOR, Fp::from(2u64));
+        assert_eq!(ROOT_OF_UNITY, -Fp::from(1u64));
+        assert_eq!(
+            BigUint::from(BigInt::new(MODULUS_PLUS_ONE_DIV_FOUR)),
+            (BigUint::from(<Fp as PrimeField>::MODULUS) + 1u8) / 4u8
+        );
+    }
+
+    // The template imports `ark_ff::Fp`, which shadows ours.
+    ark_algebra_test_templates::test_field!(ark_fp_field; crate::Fp; prime);
+}
@@ -0,0 +1,369 @@
+//! `ark_ff` field traits for [`Fp12`], the target field of the pairing.
+
+use core::{
+    array,
+    cmp::Ordering,
+    hash::{Hash, Hasher},
+    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Sub, SubAssign},
+};
+
+use ark_ff::{CyclotomicMultSubgroup, Field, LegendreSymbol, One, SqrtPrecomputation, Zero};
+use ark_serialize::{
+    CanonicalDeserialize, CanonicalDeserializeWithFlags, CanonicalSerialize,
+    CanonicalSerializeWithFlags, EmptyFlags, Flags, Read, SerializationError, Valid, Validate,
+    Write,
+};
+use ark_std::rand::{distributions::Standard, prelude::Distribution, Rng};
+use blst::{blst_fp12_cyclotomic_sqr, blst_fp12_mul};
+use zeroize::Zeroize;
+
+use crate::{
+    fp::{Fp, R, ZERO},
+    fp12::Fp12,
+    fp2::Fp2,
+    fp6::Fp6,
+};
+
+const FP2_ZERO: Fp2 = Fp2::new(ZERO, ZERO);
+const FP6_ZERO: Fp6 = Fp6::new(FP2_ZERO, FP2_ZERO, FP2_ZERO);
+
+impl Fp12 {
+    /// The coefficients over `Fp`, starting with the constant term.
+    fn coeffs(&self) -> [Fp; 12] {
+        let mut out = [ZERO; 12];
+        for (i, fp6) in self.0.fp6.iter().enumerate() {
+            for (j, fp2) in fp6.fp2.iter().enumerate() {
+                for (k, fp) in fp2.fp.iter().enumerate() {
+                    out[6 * i + 2 * j + k] = Fp(*fp);
+                }
+            }
+        }
+        out
+    }
+
+    fn from_coeffs(coeffs: &[Fp]) -> Fp12 {
+        let mut out = <Fp12 as Field>::ZERO;
+        for (i, fp6) in out.0.fp6.iter_mut().enumerate() {
+            for (j, fp2) in fp6.fp2.iter_mut().enumerate() {
+                for (k, fp) in fp2.fp.iter_mut().enumerate() {
+                    *fp = coeffs[6 * i + 2 * j + k].0;
+                }
+            }
+        }
+        out
+    }
+}
+
+impl Hash for Fp12 {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.coeffs().hash(state);
+    }
+}
+
+/// Orders elements by their coefficients, starting with the highest one, like the arkworks
+/// extension fields do.
+impl Ord for Fp12 {
+    fn cmp(&self, other: &Fp12) -> Ordering {
+        self.coeffs().iter().rev().cmp(other.coeffs().iter().rev())
+    }
+}
+
+impl PartialOrd for Fp12 {
+    fn partial_cmp(&self, other: &Fp12) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Zeroize for Fp12 {
+    fn zeroize(&mut self) {
+        for fp6 in self.0.fp6.iter_mut() {
+            for fp2 in fp6.fp2.iter_mut() {
+                for fp in fp2.fp.iter_mut() {
+                    fp.l.zeroize();
+                }
+            }
+        }
+    }
+}
+
+impl Zero for Fp12 {
+    #[inline]
+    fn zero() -> Self {
+        <Fp12 as Field>::ZERO
+    }
+
+    #[inline]
+    fn is_zero(&self) -> bool {
+        ff::Field::is_zero(self).into()
+    }
+}
+
+impl One for Fp12 {
+    #[inline]
+    fn one() -> Self {
+        <Fp12 as Field>::ONE
+    }
+
+    #[inline]
+    fn is_one(&self) -> bool {
+        *self == <Fp12 as Field>::ONE
+    }
+}
+
+impl Div<&Fp12> for &Fp12 {
+    type Output = Fp12;
+
+    #[inline]
+    fn div(self, rhs: &Fp12) -> Fp12 {
+        let mut out = *self;
+        out /= rhs;
+        out
+    }
+}
+
+impl DivAssign<&Fp12> for Fp12 {
+    /// Panics if `rhs` is zero.
+    #[inline]
+    fn div_assign(&mut self, rhs: &Fp12) {
+        let inv = ff::Field::invert(rhs).unwrap();
+        unsafe { blst_fp12_mul(&mut self.0, &self.0, &inv.0) };
+    }
+}
+
+impl_div!(Fp12);
+impl_mut_ref_ops!(Fp12);
+
+impl From<u128> for Fp12 {
+    fn from(val: u128) -> Fp12 {
+        Fp12::from(Fp::from(val))
+    }
+}
+
+impl_from_small_ints!(Fp12);
+
+impl Distribution<Fp12> for Standard {
+    #[inline]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Fp12 {
+        <Fp12 as ff::Field>::random(rng)
+    }
+}
+
+impl Field for Fp12 {
+    type BasePrimeField = Fp;
+    type BasePrimeFieldIter = array::IntoIter<Fp, 12>;
+
+    /// Square roots are not supported, as in arkworks' own `Fp12`.
+    const SQRT_PRECOMP: Option<SqrtPrecomputation<Self>> = None;
+    const ZERO: Self = Fp12::new(FP6_ZERO, FP6_ZERO);
+    const ONE: Self = Fp12::new(Fp6::new(Fp2::new(R, ZERO), FP2_ZERO, FP2_ZERO), FP6_ZERO);
+
+    fn extension_degree() -> u64 {
+        12
+    }
+
+    fn to_base_prime_field_elements(&self) -> Self::BasePrimeFieldIter {
+        IntoIterator::into_iter(self.coeffs())
+    }
+
+    fn from_base_prime_field_elems(elems: &[Self::BasePrimeField]) -> Option<Self> {
+        if elems.len() != 12 {
+            return None;
+        }
+        Some(Fp12::from_coeffs(elems))
+    }
+
+    fn from_base_prime_field(elem: Self::BasePrimeField) -> Self {
+        Fp12::from(elem)
+    }
+
+    #[inline]
+    fn double(&self) -> Self {
+        ff::Field::double(self)
+    }
+
+    #[inline]
+    fn double_in_place(&mut self) -> &mut Self {
+        *self = ff::Field::double(self);
+        self
+    }
+
+    #[inline]
+    fn neg_in_place(&mut self) -> &mut Self {
+        *self = -*self;
+        self
+    }
+
+    /// Splits `bytes` evenly between the coefficients, the flags are read from the last one.
+    fn from_random_bytes_with_flags<F: Flags>(bytes: &[u8]) -> Option<(Self, F)> {
+        let chunk = bytes.len() / 12;
+        let mut coeffs = [ZERO; 12];
+        for (i, c) in coeffs.iter_mut().take(11).enumerate() {
+            *c = Fp::from_random_bytes(&bytes[i * chunk..(i + 1) * chunk])?;
+        }
+        let (last, flags) = Fp::from_random_bytes_with_flags(&bytes[11 * chunk..])?;
+        coeffs[11] = last;
+        Some((Fp12::from_coeffs(&coeffs), flags))
+    }
+
+    /// An element is a square if and only if its norm over `Fp` is.
+    fn legendre(&self) -> LegendreSymbol {
+        let norm: Fp12 = (0..12)
+            .map(|power| {
+                let mut conjugate = *self;
+                Fp12::frobenius_map(&mut conjugate, power);
+                conjugate
+            })
+            .product();
+        norm.coeffs()[0].legendre()
+    }
+
+    #[inline]
+    fn square(&self) -> Self {
+        ff::Field::square(self)
+    }
+
+    #[inline]
+    fn square_in_place(&mut self) -> &mut Self {
+        *self = ff::Field::square(self);
+        self
+    }
+
+    #[inline]
+    fn inverse(&self) -> Option<Self> {
+        ff::Field::invert(self).into()
+    }
+
+    fn inverse_in_place(&mut self) -> Option<&mut Self> {
+        self.inverse().map(move |inv| {
+            *self = inv;
+            self
+        })
+    }
+
+    #[inline]
+    fn frobenius_map_in_place(&mut self, power: usize) {
+        Fp12::frobenius_map(self, power);
+    }
+}
+
+impl CyclotomicMultSubgroup for Fp12 {
+    const INVERSE_IS_FAST: bool = true;
+
+    fn cyclotomic_square_in_place(&mut self) -> &mut Self {
+        unsafe { blst_fp12_cyclotomic_sqr(&mut self.0, &self.0) };
+        self
+    }
+
+    fn cyclotomic_inverse_in_place(&mut self) -> Option<&mut Self> {
+        if Zero::is_zero(self) {
+            return None;
+        }
+        self.conjugate();
+        Some(self)
+    }
+}
+
+impl CanonicalSerializeWithFlags for Fp12 {
+    fn serialize_with_flags<W: Write, F: Flags>(
+        &self,
+        mut writer: W,
+        flags: F,
+    ) -> Result<(), SerializationError> {
+        let coeffs = self.coeffs();
+        for c in &coeffs[..11] {
+            c.serialize_compressed(&mut writer)?;
+        }
+        coeffs[11].serialize_with_flags(&mut writer, flags)?;
+        Ok(())
+    }
+
+    fn serialized_size_with_flags<F: Flags>(&self) -> usize {
+        11 * ZERO.compressed_size() + ZERO.serialized_size_with_flags::<F>()
+    }
+}
+
+impl CanonicalSerialize for Fp12 {
+    #[inline]
+    fn serialize_with_mode<W: Write>(
+        &self,
+        writer: W,
+        _compress: ark_serialize::Compress,
+    ) -> Result<(), SerializationError> {
+        self.serialize_with_flags(writer, EmptyFlags)
+    }
+
+    #[inline]
+    fn serialized_size(&self, _compress: ark_serialize::Compress) -> usize {
+        self.serialized_size_with_flags::<EmptyFlags>()
+    }
+}
+
+impl CanonicalDeserializeWithFlags for Fp12 {
+    fn deserialize_with_flags<R: Read, F: Flags>(
+        mut reader: R,
+    ) -> Result<(Self, F), SerializationError> {
+        let mut coeffs = [ZERO; 12];
+        for c in coeffs.iter_mut().take(11) {
+            *c = Fp::deserialize_compressed(&mut reader)?;
+        }
+        let (last, flags) = Fp::deserialize_with_flags(&mut reader)?;
+        coeffs[11] = last;
+        Ok((Fp12::from_coeffs(&coeffs), flags))
+    }
+}
+
+impl Valid for Fp12 {
+    fn check(&self) -> Result<(), SerializationError> {
+        Ok(())
+    }
+}
+
+impl CanonicalDeserialize for Fp12 {
+    fn deserialize_with_mode<R: Read>(
+        reader: R,
+        _compress: ark_serialize::Compress,
+        _validate: Validate,
+    ) -> Result<Self, SerializationError> {
+        Self::deserialize_with_flags::<R, EmptyFlags>(reader).map(|(f, _)| f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use ark_ff::{PrimeField, UniformRand};
+    use num_bigint::BigUint;
+
+    use ark_std::test_rng;
+
+    use crate::Gt;
+
+    ark_algebra_test_templates::test_field!(ark_fp12_field; Fp12);
+
+    #[test]
+    fn ark_fp12_legendre() {
+        let mut rng = test_rng();
+        let p: BigUint = <Fp as PrimeField>::MODULUS.into();
+        let exp = (p.pow(12) - 1u8) / 2u8;
+
+        assert_eq!(Fp12::zero().legendre(), LegendreSymbol::Zero);
+        for _ in 0..10 {
+            let a = Fp12::rand(&mut rng);
+            assert!(a.square().legendre().is_qr());
+            assert_eq!(a.legendre().is_qr(), a.pow(exp.to_u64_digits()).is_one());
+        }
+    }
+
+    #[test]
+    fn ark_fp12_cyclotomic() {
+        let mut rng = test_rng();
+        for _ in 0..10 {
+            let a = Fp12::from(<Gt as group::Group>::random(&mut rng));
+            assert_eq!(a.cyclotomic_square(), a.square());
+            assert_eq!(a.cyclotomic_inverse(), a.inverse());
+            assert_eq!(a.cyclotomic_exp([5u64]), a.pow([5u64]));
+        }
+        assert_eq!(Fp12::zero().cyclotomic_inverse(), None);
+    }
+}
@@ -0,0 +1,253 @@
+//! `ark_ff` field traits for [`Fp2`].
+
+use core::{
+    array,
+    hash::{Hash, Hasher},
+    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Sub, SubAssign},
+};
+
+use ark_ff::{Field, LegendreSymbol, One, SqrtPrecomputation, Zero};
+use ark_serialize::{
+    CanonicalDeserialize, CanonicalDeserializeWithFlags, CanonicalSerialize,
+    CanonicalSerializeWithFlags, EmptyFlags, Flags, Read, SerializationError, Valid, Validate,
+    Write,
+};
+use ark_std::rand::{distributions::Standard, prelude::Distribution, Rng};
+use blst::blst_fp2_mul;
+use zeroize::Zeroize;
+
+use crate::{
+    fp::{Fp, R, ZERO},
+    fp2::Fp2,
+};
+
+impl Hash for Fp2 {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.c0().hash(state);
+        self.c1().hash(state);
+    }
+}
+
+impl Zeroize for Fp2 {
+    fn zeroize(&mut self) {
+        self.0.fp[0].l.zeroize();
+        self.0.fp[1].l.zeroize();
+    }
+}
+
+impl Zero for Fp2 {
+    #[inline]
+    fn zero() -> Self {
+        <Fp2 as Field>::ZERO
+    }
+
+    #[inline]
+    fn is_zero(&self) -> bool {
+        ff::Field::is_zero(self).into()
+    }
+}
+
+impl One for Fp2 {
+    #[inline]
+    fn one() -> Self {
+        <Fp2 as Field>::ONE
+    }
+
+    #[inline]
+    fn is_one(&self) -> bool {
+        *self == <Fp2 as Field>::ONE
+    }
+}
+
+impl Div<&Fp2> for &Fp2 {
+    type Output = Fp2;
+
+    #[inline]
+    fn div(self, rhs: &Fp2) -> Fp2 {
+        let mut out = *self;
+        out /= rhs;
+        out
+    }
+}
+
+impl DivAssign<&Fp2> for Fp2 {
+    /// Panics if `rhs` is zero.
+    #[inline]
+    fn div_assign(&mut self, rhs: &Fp2) {
+        let inv = ff::Field::invert(rhs).unwrap();
+        unsafe { blst_fp2_mul(&mut self.0, &self.0, &inv.0) };
+    }
+}
+
+impl_div!(Fp2);
+impl_mut_ref_ops!(Fp2);
+
+impl From<u128> for Fp2 {
+    fn from(val: u128) -> Fp2 {
+        Fp2::from(Fp::from(val))
+    }
+}
+
+impl_from_small_ints!(Fp2);
+
+impl Distribution<Fp2> for Standard {
+    #[inline]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Fp2 {
+        <Fp2 as ff::Field>::random(rng)
+    }
+}
+
+impl Field for Fp2 {
+    type BasePrimeField = Fp;
+    type BasePrimeFieldIter = array::IntoIter<Fp, 2>;
+
+    /// Square roots are delegated to `blst_fp2_sqrt`.
+    const SQRT_PRECOMP: Option<SqrtPrecomputation<Self>> = None;
+    const ZERO: Self = Fp2::new(ZERO, ZERO);
+    const ONE: Self = Fp2::new(R, ZERO);
+
+    fn extension_degree() -> u64 {
+        2
+    }
+
+    fn to_base_prime_field_elements(&self) -> Self::BasePrimeFieldIter {
+        IntoIterator::into_iter([self.c0(), self.c1()])
+    }
+
+    fn from_base_prime_field_elems(elems: &[Self::BasePrimeField]) -> Option<Self> {
+        if elems.len() != 2 {
+            return None;
+        }
+        Some(Fp2::new(elems[0], elems[1]))
+    }
+
+    fn from_base_prime_field(elem: Self::BasePrimeField) -> Self {
+        Fp2::from(elem)
+    }
+
+    #[inline]
+    fn double(&self) -> Self {
+        ff::Field::double(self)
+    }
+
+    #[inline]
+    fn double_in_place(&mut self) -> &mut Self {
+        *self = ff::Field::double(self);
+        self
+    }
+
+    #[inline]
+    fn neg_in_place(&mut self) -> &mut Self {
+        *self = -*self;
+        self
+    }
+
+    fn from_random_bytes_with_flags<F: Flags>(bytes: &[u8]) -> Option<(Self, F)> {
+        let split_at = bytes.len() / 2;
+        let c0 = Fp::from_random_bytes(&bytes[..split_at])?;
+        let (c1, flags) = Fp::from_random_bytes_with_flags(&bytes[split_at..])?;
+        Some((Fp2::new(c0, c1), flags))
+    }
+
+    fn legendre(&self) -> LegendreSymbol {
+        self.norm().legendre()
+    }
+
+    #[inline]
+    fn sqrt(&self) -> Option<Self> {
+        ff::Field::sqrt(self).into()
+    }
+
+    #[inline]
+    fn square(&self) -> Self {
+        ff::Field::square(self)
+    }
+
+    #[inline]
+    fn square_in_place(&mut self) -> &mut Self {
+        *self = ff::Field::square(self);
+        self
+    }
+
+    #[inline]
+    fn inverse(&self) -> Option<Self> {
+        ff::Field::invert(self).into()
+    }
+
+    fn inverse_in_place(&mut self) -> Option<&mut Self> {
+        self.inverse().map(move |inv| {
+            *self = inv;
+            self
+        })
+    }
+
+    #[inline]
+    fn frobenius_map_in_place(&mut self, power: usize) {
+        Fp2::frobenius_map(self, power);
+    }
+}
+
+impl CanonicalSerializeWithFlags for Fp2 {
+    fn serialize_with_flags<W: Write, F: Flags>(
+        &self,
+        mut writer: W,
+        flags: F,
+    ) -> Result<(), SerializationError> {
+        self.c0().serialize_compressed(&mut writer)?;
+        self.c1().serialize_with_flags(&mut writer, flags)?;
+        Ok(())
+    }
+
+    fn serialized_size_with_flags<F: Flags>(&self) -> usize {
+        self.c0().compressed_size() + self.c1().serialized_size_with_flags::<F>()
+    }
+}
+
+impl CanonicalSerialize for Fp2 {
+    #[inline]
+    fn serialize_with_mode<W: Write>(
+        &self,
+        writer: W,
+        _compress: ark_serialize::Compress,
+    ) -> Result<(), SerializationError> {
+        self.serialize_with_flags(writer, EmptyFlags)
+    }
+
+    #[inline]
+    fn serialized_size(&self, _compress: ark_serialize::Compress) -> usize {
+        self.serialized_size_with_flags::<EmptyFlags>()
+    }
+}
+
+impl CanonicalDeserializeWithFlags for Fp2 {
+    fn deserialize_with_flags<R: Read, F: Flags>(
+        mut reader: R,
+    ) -> Result<(Self, F), SerializationError> {
+        let c0 = Fp::deserialize_compressed(&mut reader)?;
+        let (c1, flags) = Fp::deserialize_with_flags(&mut reader)?;
+        Ok((Fp2::new(c0, c1), flags))
+    }
+}
+
+impl Valid for Fp2 {
+    fn check(&self) -> Result<(), SerializationError> {
+        Ok(())
+    }
+}
+
+impl CanonicalDeserialize for Fp2 {
+    fn deserialize_with_mode<R: Read>(
+        reader: R,
+        _compress: ark_serialize::Compress,
+        _validate: Validate,
+    ) -> Result<Self, SerializationError> {
+        Self::deserialize_with_flags::<R, EmptyFlags>(reader).map(|(f, _)| f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    ark_algebra_test_templates::test_field!(ark_fp2_field; Fp2);
+}
@@ -0,0 +1,339 @@
+//! `ark_ec` curve traits for [`G1Affine`] and [`G1Projective`].
+
+use core::hash::{Hash, Hasher};
+
+use ark_ec::{
+    scalar_mul::{variable_base::VariableBaseMSM, ScalarMul},
+    short_weierstrass::SWFlags,
+    AffineRepr, CurveConfig, CurveGroup, Group,
+};
+use ark_ff::{BigInt, Field, Zero};
+use ark_serialize::{
+    CanonicalDeserialize, CanonicalSerialize, Compress, Read, SerializationError, Valid, Validate,
+    Write,
+};
+use ark_std::rand::{distributions::Standard, prelude::Distribution, Rng};
+use blst::*;
+use group::{prime::PrimeCurveAffine, Curve};
+use zeroize::Zeroize;
+
+use crate::{
+    fp::Fp,
+    g1::{p1s_mult_pippenger, COMPRESSED_SIZE, UNCOMPRESSED_SIZE},
+    G1Affine, G1Projective, Scalar,
+};
+
+/// The arkworks curve configuration of G1.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct G1Config;
+
+impl CurveConfig for G1Config {
+    type BaseField = Fp;
+    type ScalarField = Scalar;
+
+    /// (x - 1)^2 / 3
+    const COFACTOR: &'static [u64] = &[0x8c00_aaab_0000_aaab, 0x396c_8c00_5555_e156];
+
+    /// COFACTOR^{-1} mod r
+    const COFACTOR_INV: Scalar = Scalar(blst_fr {
+        l: [
+            0x0402_29a3_3c46_652b,
+            0x0fff_4aed_dbe1_0862,
+            0x2442_d96f_e6ff_2893,
+            0x471e_70ef_ea87_5ef8,
+        ],
+    });
+}
+
+/// Multiplies `p` by the little-endian integer `limbs`.
+fn mul_limbs(p: &G1Projective, limbs: &[u64]) -> G1Projective {
+    let bytes: Vec<u8> = limbs.iter().flat_map(|l| l.to_le_bytes()).collect();
+    let mut out = blst_p1::default();
+
+    unsafe { blst_p1_mult(&mut out, &p.0, bytes.as_ptr(), bytes.len() * 8) };
+
+    G1Projective(out)
+}
+
+impl Hash for G1Affine {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.to_compressed().hash(state);
+    }
+}
+
+impl Hash for G1Projective {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        G1Affine::from(self).hash(state);
+    }
+}
+
+impl Zeroize for G1Affine {
+    fn zeroize(&mut self) {
+        self.0.x.l.zeroize();
+        self.0.y.l.zeroize();
+    }
+}
+
+impl Zeroize for G1Projective {
+    fn zeroize(&mut self) {
+        self.0.x.l.zeroize();
+        self.0.y.l.zeroize();
+        self.0.z.l.zeroize();
+    }
+}
+
+impl Zero for G1Projective {
+    #[inline]
+    fn zero() -> Self {
+        <G1Projective as group::Group>::identity()
+    }
+
+    #[inline]
+    fn is_zero(&self) -> bool {
+        <G1Projective as group::Group>::is_identity(self).into()
+    }
+}
+
+impl Distribution<G1Projective> for Standard {
+    #[inline]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> G1Projective {
+        <G1Projective as group::Group>::random(rng)
+    }
+}
+
+impl Distribution<G1Affine> for Standard {
+    #[inline]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> G1Affine {
+        <G1Projective as group::Group>::random(rng).to_affine()
+    }
+}
+
+impl Group for G1Projective {
+    type ScalarField = Scalar;
+
+    #[inline]
+    fn generator() -> Self {
+        <G1Projective as group::Group>::generator()
+    }
+
+    #[inline]
+    fn double_in_place(&mut self) -> &mut Self {
+        *self = <G1Projective as group::Group>::double(self);
+        self
+    }
+
+    #[inline]
+    fn mul_bigint(&self, other: impl AsRef<[u64]>) -> Self {
+        mul_limbs(self, other.as_ref())
+    }
+}
+
+impl CurveGroup for G1Projective {
+    type Config = G1Config;
+    type BaseField = Fp;
+    type Affine = G1Affine;
+    type FullGroup = ();
+
+    fn normalize_batch(v: &[Self]) -> Vec<G1Affine> {
+        let mut out = vec![G1Affine::identity(); v.len()];
+        Curve::batch_normalize(v, &mut out);
+        out
+    }
+}
+
+impl ScalarMul for G1Projective {
+    type MulBase = G1Affine;
+
+    const NEGATION_IS_CHEAP: bool = true;
+
+    fn batch_convert_to_mul_base(bases: &[Self]) -> Vec<G1Affine> {
+        Self::normalize_batch(bases)
+    }
+}
+
+impl VariableBaseMSM for G1Projective {
+    /// Computes the multi-scalar multiplication with blst's Pippenger implementation, see
+    /// [`G1Affine::multi_exp`]. Extra bases or scalars are ignored.
+    fn msm_unchecked(bases: &[G1Affine], scalars: &[Scalar]) -> Self {
+        G1Affine::multi_exp(bases, scalars)
+    }
+
+    /// Like [`VariableBaseMSM::msm_unchecked`], but the scalars may be unreduced.
+    fn msm_bigint(bases: &[G1Affine], bigints: &[BigInt<4>]) -> Self {
+        let n = bases.len().min(bigints.len());
+
+        let mut scalar_bytes: Vec<u8> = Vec::with_capacity(n * 32);
+        for b in &bigints[..n] {
+            scalar_bytes.extend(b.0.iter().flat_map(|l| l.to_le_bytes()));
+        }
+
+        p1s_mult_pippenger(&bases[..n], &scalar_bytes, 256)
+    }
+}
+
+impl AffineRepr for G1Affine {
+    type Config = G1Config;
+    type ScalarField = Scalar;
+    type BaseField = Fp;
+    type Group = G1Projective;
+
+    fn xy(&self) -> Option<(&Fp, &Fp)> {
+        if bool::from(self.is_identity()) {
+            return None;
+        }
+
+        // Safe because `Fp` is a transparent wrapper around `blst_fp`.
+        let x = unsafe { &*(&self.0.x as *const blst_fp as *const Fp) };
+        let y = unsafe { &*(&self.0.y as *const blst_fp as *const Fp) };
+        Some((x, y))
+    }
+
+    #[inline]
+    fn zero() -> Self {
+        G1Affine::identity()
+    }
+
+    #[inline]
+    fn generator() -> Self {
+        <G1Affine as PrimeCurveAffine>::generator()
+    }
+
+    /// Interprets `bytes` as an x-coordinate with flags, as arkworks does. The resulting point
+    /// is on the curve, but not necessarily in the prime order subgroup.
+    fn from_random_bytes(bytes: &[u8]) -> Option<Self> {
+        let (x, flags) = Fp::from_random_bytes_with_flags::<SWFlags>(bytes)?;
+        if x.is_zero() && flags.is_infinity() {
+            return Some(G1Affine::identity());
+        }
+
+        let greatest = flags.is_positive()?;
+        let y = (x.square() * x + Fp::from(4u64)).sqrt()?;
+        let neg_y = -y;
+        let (smaller, larger) = if y < neg_y { (y, neg_y) } else { (neg_y, y) };
+        let y = if greatest { larger } else { smaller };
+        Some(G1Affine::from_raw_unchecked(x, y, false))
+    }
+
+    #[inline]
+    fn mul_bigint(&self, by: impl AsRef<[u64]>) -> G1Projective {
+        mul_limbs(&G1Projective::from(self), by.as_ref())
+    }
+
+    fn clear_cofactor(&self) -> Self {
+        G1Projective::from(self).clear_cofactor().to_affine()
+    }
+
+    fn mul_by_cofactor_to_group(&self) -> G1Projective {
+        self.mul_bigint(G1Config::COFACTOR)
+    }
+}
+
+impl CanonicalSerialize for G1Affine {
+    /// Uses the same encodings as [`G1Affine::to_compressed`] and
+    /// [`G1Affine::to_uncompressed`].
+    fn serialize_with_mode<W: Write>(
+        &self,
+        mut writer: W,
+        compress: Compress,
+    ) -> Result<(), SerializationError> {
+        match compress {
+            Compress::Yes => writer.write_all(&self.to_compressed())?,
+            Compress::No => writer.write_all(&self.to_uncompressed())?,
+        }
+        Ok(())
+    }
+
+    fn serialized_size(&self, compress: Compress) -> usize {
+        match compress {
+            Compress::Yes => COMPRESSED_SIZE,
+            Compress::No => UNCOMPRESSED_SIZE,
+        }
+    }
+}
+
+impl Valid for G1Affine {
+    fn check(&self) -> Result<(), SerializationError> {
+        if bool::from(self.is_on_curve() & self.is_torsion_free()) {
+            Ok(())
+        } else {
+            Err(SerializationError::InvalidData)
+        }
+    }
+}
+
+impl CanonicalDeserialize for G1Affine {
+    fn deserialize_with_mode<R: Read>(
+        mut reader: R,
+        compress: Compress,
+        validate: Validate,
+    ) -> Result<Self, SerializationError> {
+        let p = match compress {
+            Compress::Yes => {
+                let mut bytes = [0u8; COMPRESSED_SIZE];
+                reader.read_exact(&mut bytes)?;
+                G1Affine::from_compressed_unchecked(&bytes)
+            }
+            Compress::No => {
+                let mut bytes = [0u8; UNCOMPRESSED_SIZE];
+                reader.read_exact(&mut bytes)?;
+                G1Affine::from_uncompressed_unchecked(&bytes)
+            }
+        };
+        let p: G1Affine = Option::from(p).ok_or(SerializationError::InvalidData)?;
+
+        if let Validate::Yes = validate {
+            p.check()?;
+        }
+        Ok(p)
+    }
+}
+
+impl CanonicalSerialize for G1Projective {
+    fn serialize_with_mode<W: Write>(
+        &self,
+        writer: W,
+        compress: Compress,
+    ) -> Result<(), SerializationError> {
+        G1Affine::from(self).serialize_with_mode(writer, compress)
+    }
+
+    fn serialized_size(&self, compress: Compress) -> usize {
+        G1Affine::from(self).serialized_size(compress)
+    }
+}
+
+impl Valid for G1Projective {
+    fn check(&self) -> Result<(), SerializationError> {
+        G1Affine::from(self).check()
+    }
+}
+
+impl CanonicalDeserialize for G1Projective {
+    fn deserialize_with_mode<R: Read>(
+        reader: R,
+        compress: Compress,
+        validate: Validate,
+    ) -> Result<Self, SerializationError> {
+        G1Affine::deserialize_with_mode(reader, compress, validate).map(G1Projective::from)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    ark_algebra_test_templates::test_group!(ark_g1_group; G1Projective);
+    ark_algebra_test_templates::test_group!(ark_g1_msm; G1Projective; msm);
+
+    #[test]
+    fn ark_g1_curve_tests() {
+        crate::tests::ark_curve::curve_tests::<G1Projective>();
+    }
+
+    #[test]
+    fn ark_g1_xy() {
+        let g = <G1Affine as AffineRepr>::generator();
+        assert_eq!(g.xy(), Some((&g.x(), &g.y())));
+        assert_eq!(<G1Affine as AffineRepr>::zero().xy(), None);
+    }
+}
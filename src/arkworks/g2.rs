@@ -0,0 +1,351 @@
+//! `ark_ec` curve traits for [`G2Affine`] and [`G2Projective`].
+
+use core::hash::{Hash, Hasher};
+
+use ark_ec::{
+    scalar_mul::{variable_base::VariableBaseMSM, ScalarMul},
+    short_weierstrass::SWFlags,
+    AffineRepr, CurveConfig, CurveGroup, Group,
+};
+use ark_ff::{BigInt, Field, Zero};
+use ark_serialize::{
+    CanonicalDeserialize, CanonicalSerialize, Compress, Read, SerializationError, Valid, Validate,
+    Write,
+};
+use ark_std::rand::{distributions::Standard, prelude::Distribution, Rng};
+use blst::*;
+use group::{prime::PrimeCurveAffine, Curve};
+use zeroize::Zeroize;
+
+use crate::{
+    fp::Fp,
+    fp2::Fp2,
+    g2::{p2s_mult_pippenger, COMPRESSED_SIZE, UNCOMPRESSED_SIZE},
+    G2Affine, G2Projective, Scalar,
+};
+
+/// The arkworks curve configuration of G2.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct G2Config;
+
+impl CurveConfig for G2Config {
+    type BaseField = Fp2;
+    type ScalarField = Scalar;
+
+    /// (x^8 - 4 x^7 + 5 x^6 - 4 x^4 + 6 x^3 - 4 x^2 - 4 x + 13) / 9
+    const COFACTOR: &'static [u64] = &[
+        0xcf1c_38e3_1c72_38e5,
+        0x1616_ec6e_786f_0c70,
+        0x2153_7e29_3a66_91ae,
+        0xa628_f1cb_4d9e_82ef,
+        0xa68a_205b_2e5a_7ddf,
+        0xcd91_de45_4708_5aba,
+        0x091d_5079_2876_a202,
+        0x05d5_43a9_5414_e7f1,
+    ];
+
+    /// COFACTOR^{-1} mod r
+    const COFACTOR_INV: Scalar = Scalar(blst_fr {
+        l: [
+            0x5da0_0e03_6302_48a8,
+            0x1218_ee4f_fc99_5aea,
+            0x2228_56d8_d4f7_d76f,
+            0x52aa_152f_23fe_94d4,
+        ],
+    });
+}
+
+/// Multiplies `p` by the little-endian integer `limbs`.
+fn mul_limbs(p: &G2Projective, limbs: &[u64]) -> G2Projective {
+    let bytes: Vec<u8> = limbs.iter().flat_map(|l| l.to_le_bytes()).collect();
+    let mut out = blst_p2::default();
+
+    unsafe { blst_p2_mult(&mut out, &p.0, bytes.as_ptr(), bytes.len() * 8) };
+
+    G2Projective(out)
+}
+
+impl Hash for G2Affine {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.to_compressed().hash(state);
+    }
+}
+
+impl Hash for G2Projective {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        G2Affine::from(self).hash(state);
+    }
+}
+
+impl Zeroize for G2Affine {
+    fn zeroize(&mut self) {
+        for fp in self.0.x.fp.iter_mut().chain(self.0.y.fp.iter_mut()) {
+            fp.l.zeroize();
+        }
+    }
+}
+
+impl Zeroize for G2Projective {
+    fn zeroize(&mut self) {
+        let coords = [&mut self.0.x, &mut self.0.y, &mut self.0.z];
+        for fp in IntoIterator::into_iter(coords).flat_map(|c| c.fp.iter_mut()) {
+            fp.l.zeroize();
+        }
+    }
+}
+
+impl Zero for G2Projective {
+    #[inline]
+    fn zero() -> Self {
+        <G2Projective as group::Group>::identity()
+    }
+
+    #[inline]
+    fn is_zero(&self) -> bool {
+        <G2Projective as group::Group>::is_identity(self).into()
+    }
+}
+
+impl Distribution<G2Projective> for Standard {
+    #[inline]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> G2Projective {
+        <G2Projective as group::Group>::random(rng)
+    }
+}
+
+impl Distribution<G2Affine> for Standard {
+    #[inline]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> G2Affine {
+        <G2Projective as group::Group>::random(rng).to_affine()
+    }
+}
+
+impl Group for G2Projective {
+    type ScalarField = Scalar;
+
+    #[inline]
+    fn generator() -> Self {
+        <G2Projective as group::Group>::generator()
+    }
+
+    #[inline]
+    fn double_in_place(&mut self) -> &mut Self {
+        *self = <G2Projective as group::Group>::double(self);
+        self
+    }
+
+    #[inline]
+    fn mul_bigint(&self, other: impl AsRef<[u64]>) -> Self {
+        mul_limbs(self, other.as_ref())
+    }
+}
+
+impl CurveGroup for G2Projective {
+    type Config = G2Config;
+    type BaseField = Fp2;
+    type Affine = G2Affine;
+    type FullGroup = ();
+
+    fn normalize_batch(v: &[Self]) -> Vec<G2Affine> {
+        let mut out = vec![G2Affine::identity(); v.len()];
+        Curve::batch_normalize(v, &mut out);
+        out
+    }
+}
+
+impl ScalarMul for G2Projective {
+    type MulBase = G2Affine;
+
+    const NEGATION_IS_CHEAP: bool = true;
+
+    fn batch_convert_to_mul_base(bases: &[Self]) -> Vec<G2Affine> {
+        Self::normalize_batch(bases)
+    }
+}
+
+impl VariableBaseMSM for G2Projective {
+    /// Computes the multi-scalar multiplication with blst's Pippenger implementation, see
+    /// [`G2Affine::multi_exp`]. Extra bases or scalars are ignored.
+    fn msm_unchecked(bases: &[G2Affine], scalars: &[Scalar]) -> Self {
+        G2Affine::multi_exp(bases, scalars)
+    }
+
+    /// Like [`VariableBaseMSM::msm_unchecked`], but the scalars may be unreduced.
+    fn msm_bigint(bases: &[G2Affine], bigints: &[BigInt<4>]) -> Self {
+        let n = bases.len().min(bigints.len());
+
+        let mut scalar_bytes: Vec<u8> = Vec::with_capacity(n * 32);
+        for b in &bigints[..n] {
+            scalar_bytes.extend(b.0.iter().flat_map(|l| l.to_le_bytes()));
+        }
+
+        p2s_mult_pippenger(&bases[..n], &scalar_bytes, 256)
+    }
+}
+
+impl AffineRepr for G2Affine {
+    type Config = G2Config;
+    type ScalarField = Scalar;
+    type BaseField = Fp2;
+    type Group = G2Projective;
+
+    fn xy(&self) -> Option<(&Fp2, &Fp2)> {
+        if bool::from(self.is_identity()) {
+            return None;
+        }
+
+        // Safe because `Fp2` is a transparent wrapper around `blst_fp2`.
+        let x = unsafe { &*(&self.0.x as *const blst_fp2 as *const Fp2) };
+        let y = unsafe { &*(&self.0.y as *const blst_fp2 as *const Fp2) };
+        Some((x, y))
+    }
+
+    #[inline]
+    fn zero() -> Self {
+        G2Affine::identity()
+    }
+
+    #[inline]
+    fn generator() -> Self {
+        <G2Affine as PrimeCurveAffine>::generator()
+    }
+
+    /// Interprets `bytes` as an x-coordinate with flags, as arkworks does. The resulting point
+    /// is on the curve, but not necessarily in the prime order subgroup.
+    fn from_random_bytes(bytes: &[u8]) -> Option<Self> {
+        let (x, flags) = Fp2::from_random_bytes_with_flags::<SWFlags>(bytes)?;
+        if x.is_zero() && flags.is_infinity() {
+            return Some(G2Affine::identity());
+        }
+
+        let greatest = flags.is_positive()?;
+        let y = (x.square() * x + Fp2::new(Fp::from(4u64), Fp::from(4u64))).sqrt()?;
+        let neg_y = -y;
+        let (smaller, larger) = if y < neg_y { (y, neg_y) } else { (neg_y, y) };
+        let y = if greatest { larger } else { smaller };
+        Some(G2Affine::from_raw_unchecked(x, y, false))
+    }
+
+    #[inline]
+    fn mul_bigint(&self, by: impl AsRef<[u64]>) -> G2Projective {
+        mul_limbs(&G2Projective::from(self), by.as_ref())
+    }
+
+    fn clear_cofactor(&self) -> Self {
+        G2Projective::from(self).clear_cofactor().to_affine()
+    }
+
+    fn mul_by_cofactor_to_group(&self) -> G2Projective {
+        self.mul_bigint(G2Config::COFACTOR)
+    }
+}
+
+impl CanonicalSerialize for G2Affine {
+    /// Uses the same encodings as [`G2Affine::to_compressed`] and
+    /// [`G2Affine::to_uncompressed`].
+    fn serialize_with_mode<W: Write>(
+        &self,
+        mut writer: W,
+        compress: Compress,
+    ) -> Result<(), SerializationError> {
+        match compress {
+            Compress::Yes => writer.write_all(&self.to_compressed())?,
+            Compress::No => writer.write_all(&self.to_uncompressed())?,
+        }
+        Ok(())
+    }
+
+    fn serialized_size(&self, compress: Compress) -> usize {
+        match compress {
+            Compress::Yes => COMPRESSED_SIZE,
+            Compress::No => UNCOMPRESSED_SIZE,
+        }
+    }
+}
+
+impl Valid for G2Affine {
+    fn check(&self) -> Result<(), SerializationError> {
+        if bool::from(self.is_on_curve() & self.is_torsion_free()) {
+            Ok(())
+        } else {
+            Err(SerializationError::InvalidData)
+        }
+    }
+}
+
+impl CanonicalDeserialize for G2Affine {
+    fn deserialize_with_mode<R: Read>(
+        mut reader: R,
+        compress: Compress,
+        validate: Validate,
+    ) -> Result<Self, SerializationError> {
+        let p = match compress {
+            Compress::Yes => {
+                let mut bytes = [0u8; COMPRESSED_SIZE];
+                reader.read_exact(&mut bytes)?;
+                G2Affine::from_compressed_unchecked(&bytes)
+            }
+            Compress::No => {
+                let mut bytes = [0u8; UNCOMPRESSED_SIZE];
+                reader.read_exact(&mut bytes)?;
+                G2Affine::from_uncompressed_unchecked(&bytes)
+            }
+        };
+        let p: G2Affine = Option::from(p).ok_or(SerializationError::InvalidData)?;
+
+        if let Validate::Yes = validate {
+            p.check()?;
+        }
+        Ok(p)
+    }
+}
+
+impl CanonicalSerialize for G2Projective {
+    fn serialize_with_mode<W: Write>(
+        &self,
+        writer: W,
+        compress: Compress,
+    ) -> Result<(), SerializationError> {
+        G2Affine::from(self).serialize_with_mode(writer, compress)
+    }
+
+    fn serialized_size(&self, compress: Compress) -> usize {
+        G2Affine::from(self).serialized_size(compress)
+    }
+}
+
+impl Valid for G2Projective {
+    fn check(&self) -> Result<(), SerializationError> {
+        G2Affine::from(self).check()
+    }
+}
+
+impl CanonicalDeserialize for G2Projective {
+    fn deserialize_with_mode<R: Read>(
+        reader: R,
+        compress: Compress,
+        validate: Validate,
+    ) -> Result<Self, SerializationError> {
+        G2Affine::deserialize_with_mode(reader, compress, validate).map(G2Projective::from)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    ark_algebra_test_templates::test_group!(ark_g2_group; G2Projective);
+    ark_algebra_test_templates::test_group!(ark_g2_msm; G2Projective; msm);
+
+    #[test]
+    fn ark_g2_curve_tests() {
+        crate::tests::ark_curve::curve_tests::<G2Projective>();
+    }
+
+    #[test]
+    fn ark_g2_xy() {
+        let g = <G2Affine as AffineRepr>::generator();
+        assert_eq!(g.xy(), Some((&g.x(), &g.y())));
+        assert_eq!(<G2Affine as AffineRepr>::zero().xy(), None);
+    }
+}
@@ -0,0 +1,470 @@
+//! Implementations of the [arkworks](https://arkworks.rs) algebra traits, enabled by the
+//! `arkworks` feature.
+//!
+//! The arkworks traits share many method names with the `ff` and `group` traits, so they
+//! are kept in this module to avoid ambiguity in the rest of the crate.
+
+/// Derives the by-value `Div` impls from `Div<&T> for &T`, like `impl_mul!` does for `Mul`.
+macro_rules! impl_div {
+    ($t:ident) => {
+        impl Div<&$t> for $t {
+            type Output = $t;
+
+            #[inline]
+            fn div(self, rhs: &$t) -> $t {
+                &self / rhs
+            }
+        }
+
+        impl Div<$t> for &$t {
+            type Output = $t;
+
+            #[inline]
+            fn div(self, rhs: $t) -> $t {
+                self / &rhs
+            }
+        }
+
+        impl Div<$t> for $t {
+            type Output = $t;
+
+            #[inline]
+            fn div(self, rhs: $t) -> $t {
+                &self / &rhs
+            }
+        }
+
+        impl DivAssign<$t> for $t {
+            #[inline]
+            fn div_assign(&mut self, rhs: $t) {
+                self.div_assign(&rhs);
+            }
+        }
+    };
+}
+
+/// Arkworks additionally requires every operator to accept `&mut T` operands.
+macro_rules! impl_mut_ref_ops {
+    ($t:ident) => {
+        impl_mut_ref_ops!(
+            $t,
+            Add, add, AddAssign, add_assign;
+            Sub, sub, SubAssign, sub_assign;
+            Mul, mul, MulAssign, mul_assign;
+            Div, div, DivAssign, div_assign;
+        );
+    };
+    ($t:ident, $($op:ident, $op_fn:ident, $assign:ident, $assign_fn:ident;)*) => {
+        $(
+            impl $op<&mut $t> for $t {
+                type Output = $t;
+
+                #[inline]
+                fn $op_fn(self, rhs: &mut $t) -> $t {
+                    $op::$op_fn(&self, &*rhs)
+                }
+            }
+
+            impl $assign<&mut $t> for $t {
+                #[inline]
+                fn $assign_fn(&mut self, rhs: &mut $t) {
+                    $assign::$assign_fn(self, &*rhs);
+                }
+            }
+        )*
+    };
+}
+
+/// Implements the small integer conversions arkworks requires in terms of `From<u64>`.
+///
+/// With several `From` impls for integers, an untyped literal such as `Scalar::from(7)` no longer
+/// infers `u64`, so enabling this feature anywhere in a dependency graph breaks such calls.
+macro_rules! impl_from_small_ints {
+    ($t:ident) => {
+        impl_from_small_ints!($t, u32, u16, u8, bool);
+    };
+    ($t:ident, $($int:ident),*) => {
+        $(
+            impl From<$int> for $t {
+                fn from(val: $int) -> $t {
+                    $t::from(u64::from(val))
+                }
+            }
+        )*
+    };
+}
+
+/// Implements the arkworks field, conversion and serialization traits for a prime field `$t` of
+/// `$limbs` 64-bit limbs, serialized as `$bytes` little-endian bytes. `$mul` and `$to_u64s` are
+/// the `blst` multiplication and conversion to canonical limbs.
+macro_rules! impl_ark_prime_field {
+    (
+        $t:ident,
+        limbs: $limbs:expr,
+        bytes: $bytes:expr,
+        modulus_bit_size: $bits:expr,
+        two_adicity: $two_adicity:expr,
+        sqrt_precomp: $sqrt_precomp:expr,
+        mul: $mul:ident,
+        to_u64s: $to_u64s:ident $(,)?
+    ) => {
+        impl Hash for $t {
+            fn hash<H: Hasher>(&self, state: &mut H) {
+                self.to_bytes_le().hash(state);
+            }
+        }
+
+        impl Zeroize for $t {
+            fn zeroize(&mut self) {
+                self.0.l.zeroize();
+            }
+        }
+
+        impl Zero for $t {
+            #[inline]
+            fn zero() -> Self {
+                ZERO
+            }
+
+            #[inline]
+            fn is_zero(&self) -> bool {
+                *self == ZERO
+            }
+        }
+
+        impl One for $t {
+            #[inline]
+            fn one() -> Self {
+                R
+            }
+
+            #[inline]
+            fn is_one(&self) -> bool {
+                *self == R
+            }
+        }
+
+        impl Div<&$t> for &$t {
+            type Output = $t;
+
+            #[inline]
+            fn div(self, rhs: &$t) -> $t {
+                let mut out = *self;
+                out /= rhs;
+                out
+            }
+        }
+
+        impl DivAssign<&$t> for $t {
+            /// Panics if `rhs` is zero.
+            #[inline]
+            fn div_assign(&mut self, rhs: &$t) {
+                let inv = ff::Field::invert(rhs).unwrap();
+                unsafe { $mul(&mut self.0, &self.0, &inv.0) };
+            }
+        }
+
+        impl_div!($t);
+        impl_mut_ref_ops!($t);
+
+        impl From<u128> for $t {
+            fn from(val: u128) -> $t {
+                let mut limbs = [0u64; $limbs];
+                limbs[0] = val as u64;
+                limbs[1] = (val >> 64) as u64;
+                $t::from_u64s_le(&limbs).unwrap()
+            }
+        }
+
+        impl_from_small_ints!($t);
+
+        impl From<BigInt<$limbs>> for $t {
+            /// Panics if `val` is not less than the modulus.
+            fn from(val: BigInt<$limbs>) -> $t {
+                $t::from_bigint(val).unwrap()
+            }
+        }
+
+        impl From<$t> for BigInt<$limbs> {
+            fn from(val: $t) -> BigInt<$limbs> {
+                val.into_bigint()
+            }
+        }
+
+        impl From<BigUint> for $t {
+            /// Reduces `val` modulo the modulus.
+            fn from(val: BigUint) -> $t {
+                let reduced = val % BigUint::from(<$t as PrimeField>::MODULUS);
+                let mut limbs = [0u64; $limbs];
+                for (limb, digit) in limbs.iter_mut().zip(reduced.iter_u64_digits()) {
+                    *limb = digit;
+                }
+                $t::from_u64s_le(&limbs).unwrap()
+            }
+        }
+
+        impl From<$t> for BigUint {
+            fn from(val: $t) -> BigUint {
+                BigUint::from_bytes_le(&val.to_bytes_le())
+            }
+        }
+
+        impl FromStr for $t {
+            type Err = ();
+
+            /// Parses a decimal string, rejecting leading zeroes and values that are not less than
+            /// the modulus.
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                if s.is_empty() || !s.bytes().all(|b| b.is_ascii_digit()) {
+                    return Err(());
+                }
+                if s.len() > 1 && s.starts_with('0') {
+                    return Err(());
+                }
+
+                let val = BigUint::parse_bytes(s.as_bytes(), 10).ok_or(())?;
+                if val >= BigUint::from(<$t as PrimeField>::MODULUS) {
+                    return Err(());
+                }
+                Ok($t::from(val))
+            }
+        }
+
+        impl Distribution<$t> for Standard {
+            #[inline]
+            fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> $t {
+                <$t as ff::Field>::random(rng)
+            }
+        }
+
+        impl Field for $t {
+            type BasePrimeField = Self;
+            type BasePrimeFieldIter = iter::Once<Self>;
+
+            const SQRT_PRECOMP: Option<SqrtPrecomputation<Self>> = $sqrt_precomp;
+            const ZERO: Self = ZERO;
+            const ONE: Self = R;
+
+            fn characteristic() -> &'static [u64] {
+                &MODULUS
+            }
+
+            fn extension_degree() -> u64 {
+                1
+            }
+
+            fn to_base_prime_field_elements(&self) -> Self::BasePrimeFieldIter {
+                iter::once(*self)
+            }
+
+            fn from_base_prime_field_elems(elems: &[Self::BasePrimeField]) -> Option<Self> {
+                if elems.len() != 1 {
+                    return None;
+                }
+                Some(elems[0])
+            }
+
+            fn from_base_prime_field(elem: Self::BasePrimeField) -> Self {
+                elem
+            }
+
+            #[inline]
+            fn double(&self) -> Self {
+                ff::Field::double(self)
+            }
+
+            #[inline]
+            fn double_in_place(&mut self) -> &mut Self {
+                *self = ff::Field::double(self);
+                self
+            }
+
+            #[inline]
+            fn neg_in_place(&mut self) -> &mut Self {
+                *self = -*self;
+                self
+            }
+
+            fn from_random_bytes_with_flags<F: Flags>(bytes: &[u8]) -> Option<(Self, F)> {
+                if F::BIT_SIZE > 8 {
+                    return None;
+                }
+
+                let output_byte_size = buffer_byte_size($bits + F::BIT_SIZE);
+                let mut buf = [0u8; $bytes + 1];
+                let len = bytes.len().min(output_byte_size);
+                buf[..len].copy_from_slice(&bytes[..len]);
+
+                // The flags live in the top `F::BIT_SIZE` bits of the last byte.
+                let flags_mask = u8::MAX.checked_shl(8 - (F::BIT_SIZE as u32)).unwrap_or(0);
+                let flags = buf[output_byte_size - 1] & flags_mask;
+
+                // Keep only the low `MODULUS_BIT_SIZE` bits for the element itself.
+                buf[$bytes - 1] &= u8::MAX >> (8 * $bytes - $bits);
+
+                let mut repr = [0u8; $bytes];
+                repr.copy_from_slice(&buf[..$bytes]);
+                let elem: Option<$t> = $t::from_bytes_le(&repr).into();
+                elem.zip(F::from_u8(flags))
+            }
+
+            fn legendre(&self) -> LegendreSymbol {
+                if Zero::is_zero(self) {
+                    LegendreSymbol::Zero
+                } else if self.is_quad_res().into() {
+                    LegendreSymbol::QuadraticResidue
+                } else {
+                    LegendreSymbol::QuadraticNonResidue
+                }
+            }
+
+            #[inline]
+            fn sqrt(&self) -> Option<Self> {
+                ff::Field::sqrt(self).into()
+            }
+
+            #[inline]
+            fn square(&self) -> Self {
+                ff::Field::square(self)
+            }
+
+            #[inline]
+            fn square_in_place(&mut self) -> &mut Self {
+                self.square_assign();
+                self
+            }
+
+            #[inline]
+            fn inverse(&self) -> Option<Self> {
+                ff::Field::invert(self).into()
+            }
+
+            fn inverse_in_place(&mut self) -> Option<&mut Self> {
+                self.inverse().map(move |inv| {
+                    *self = inv;
+                    self
+                })
+            }
+
+            /// The Frobenius map has no effect in a prime field.
+            #[inline]
+            fn frobenius_map_in_place(&mut self, _: usize) {}
+        }
+
+        impl FftField for $t {
+            const GENERATOR: Self = GENERATOR;
+            const TWO_ADICITY: u32 = $two_adicity;
+            const TWO_ADIC_ROOT_OF_UNITY: Self = ROOT_OF_UNITY;
+        }
+
+        impl PrimeField for $t {
+            type BigInt = BigInt<$limbs>;
+
+            const MODULUS: Self::BigInt = BigInt::new(MODULUS);
+            const MODULUS_MINUS_ONE_DIV_TWO: Self::BigInt = Self::MODULUS.divide_by_2_round_down();
+            const MODULUS_BIT_SIZE: u32 = $bits as u32;
+            const TRACE: Self::BigInt = Self::MODULUS.two_adic_coefficient();
+            const TRACE_MINUS_ONE_DIV_TWO: Self::BigInt = Self::TRACE.divide_by_2_round_down();
+
+            fn from_bigint(repr: Self::BigInt) -> Option<Self> {
+                $t::from_u64s_le(&repr.0).into()
+            }
+
+            fn into_bigint(self) -> Self::BigInt {
+                let mut out = [0u64; $limbs];
+                unsafe { $to_u64s(out.as_mut_ptr(), &self.0) };
+                BigInt::new(out)
+            }
+        }
+
+        impl CanonicalSerializeWithFlags for $t {
+            fn serialize_with_flags<W: Write, F: Flags>(
+                &self,
+                mut writer: W,
+                flags: F,
+            ) -> Result<(), SerializationError> {
+                if F::BIT_SIZE > 8 {
+                    return Err(SerializationError::NotEnoughSpace);
+                }
+
+                let output_byte_size = self.serialized_size_with_flags::<F>();
+                let mut bytes = [0u8; $bytes + 1];
+                bytes[..$bytes].copy_from_slice(&self.to_bytes_le());
+                bytes[output_byte_size - 1] |= flags.u8_bitmask();
+
+                writer.write_all(&bytes[..output_byte_size])?;
+                Ok(())
+            }
+
+            fn serialized_size_with_flags<F: Flags>(&self) -> usize {
+                buffer_byte_size($bits + F::BIT_SIZE)
+            }
+        }
+
+        impl CanonicalSerialize for $t {
+            #[inline]
+            fn serialize_with_mode<W: Write>(
+                &self,
+                writer: W,
+                _compress: ark_serialize::Compress,
+            ) -> Result<(), SerializationError> {
+                self.serialize_with_flags(writer, EmptyFlags)
+            }
+
+            #[inline]
+            fn serialized_size(&self, _compress: ark_serialize::Compress) -> usize {
+                self.serialized_size_with_flags::<EmptyFlags>()
+            }
+        }
+
+        impl CanonicalDeserializeWithFlags for $t {
+            fn deserialize_with_flags<R: Read, F: Flags>(
+                mut reader: R,
+            ) -> Result<(Self, F), SerializationError> {
+                if F::BIT_SIZE > 8 {
+                    return Err(SerializationError::NotEnoughSpace);
+                }
+
+                let output_byte_size = ZERO.serialized_size_with_flags::<F>();
+                let mut bytes = [0u8; $bytes + 1];
+                reader.read_exact(&mut bytes[..output_byte_size])?;
+                let flags = F::from_u8_remove_flags(&mut bytes[output_byte_size - 1])
+                    .ok_or(SerializationError::UnexpectedFlags)?;
+
+                let mut repr = [0u8; $bytes];
+                repr.copy_from_slice(&bytes[..$bytes]);
+                Option::from($t::from_bytes_le(&repr))
+                    .map(|f| (f, flags))
+                    .ok_or(SerializationError::InvalidData)
+            }
+        }
+
+        impl Valid for $t {
+            fn check(&self) -> Result<(), SerializationError> {
+                Ok(())
+            }
+        }
+
+        impl CanonicalDeserialize for $t {
+            fn deserialize_with_mode<R: Read>(
+                reader: R,
+                _compress: ark_serialize::Compress,
+                _validate: Validate,
+            ) -> Result<Self, SerializationError> {
+                Self::deserialize_with_flags::<R, EmptyFlags>(reader).map(|(f, _)| f)
+            }
+        }
+    };
+}
+
+mod convert;
+mod fp;
+mod fp12;
+mod fp2;
+mod g1;
+mod g2;
+mod pairing;
+mod scalar;
+
+pub use g1::G1Config;
+pub use g2::G2Config;
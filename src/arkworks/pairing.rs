@@ -0,0 +1,216 @@
+//! `ark_ec` pairing trait for [`Bls12`].
+
+use core::convert::TryFrom;
+
+use ark_ec::pairing::{MillerLoopOutput, Pairing, PairingOutput};
+use ark_serialize::{
+    CanonicalDeserialize, CanonicalSerialize, Compress, Read, SerializationError, Valid, Validate,
+    Write,
+};
+use blst::blst_fp6;
+use pairing_lib::{MillerLoopResult as _, MultiMillerLoop};
+
+use crate::{
+    fp::Fp, fp12::Fp12, fp2::Fp2, Bls12, G1Affine, G1Projective, G2Affine, G2Prepared,
+    G2Projective, Gt, MillerLoopResult, NotInSubgroupError, Scalar,
+};
+
+/// The number of line functions blst precomputes for a G2 point.
+const NUM_LINES: usize = 68;
+
+/// G1 points need no preparation, so [`G1Affine`] is used as `Pairing::G1Prepared`.
+impl From<&G1Affine> for G1Affine {
+    fn from(p: &G1Affine) -> G1Affine {
+        *p
+    }
+}
+
+impl CanonicalSerialize for G2Prepared {
+    /// Serializes the precomputed line functions, like arkworks does.
+    fn serialize_with_mode<W: Write>(
+        &self,
+        mut writer: W,
+        compress: Compress,
+    ) -> Result<(), SerializationError> {
+        self.infinity.serialize_with_mode(&mut writer, compress)?;
+        (self.lines.len() as u64).serialize_with_mode(&mut writer, compress)?;
+        for line in &self.lines {
+            for c in &line.fp2 {
+                Fp2(*c).serialize_with_mode(&mut writer, compress)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn serialized_size(&self, compress: Compress) -> usize {
+        let line_size = 3 * Fp2::default().serialized_size(compress);
+        self.infinity.serialized_size(compress) + 8 + self.lines.len() * line_size
+    }
+}
+
+impl Valid for G2Prepared {
+    fn check(&self) -> Result<(), SerializationError> {
+        let expected = if self.infinity { 0 } else { NUM_LINES };
+        if self.lines.len() == expected {
+            Ok(())
+        } else {
+            Err(SerializationError::InvalidData)
+        }
+    }
+}
+
+impl CanonicalDeserialize for G2Prepared {
+    fn deserialize_with_mode<R: Read>(
+        mut reader: R,
+        compress: Compress,
+        validate: Validate,
+    ) -> Result<Self, SerializationError> {
+        let infinity = bool::deserialize_with_mode(&mut reader, compress, validate)?;
+        let len = u64::deserialize_with_mode(&mut reader, compress, validate)? as usize;
+        if len > NUM_LINES {
+            return Err(SerializationError::InvalidData);
+        }
+
+        let mut lines = Vec::with_capacity(len);
+        for _ in 0..len {
+            let mut line = blst_fp6::default();
+            for c in line.fp2.iter_mut() {
+                *c = Fp2::deserialize_with_mode(&mut reader, compress, validate)?.0;
+            }
+            lines.push(line);
+        }
+
+        let prepared = G2Prepared { lines, infinity };
+        if let Validate::Yes = validate {
+            prepared.check()?;
+        }
+        Ok(prepared)
+    }
+}
+
+impl From<Gt> for PairingOutput<Bls12> {
+    fn from(gt: Gt) -> Self {
+        PairingOutput(gt.0)
+    }
+}
+
+impl TryFrom<PairingOutput<Bls12>> for Gt {
+    type Error = NotInSubgroupError;
+
+    /// Fails if `out` is not in the order `r` subgroup of `Fp12`.
+    fn try_from(out: PairingOutput<Bls12>) -> Result<Self, Self::Error> {
+        Gt::try_from(out.0)
+    }
+}
+
+impl From<MillerLoopResult> for MillerLoopOutput<Bls12> {
+    fn from(res: MillerLoopResult) -> Self {
+        MillerLoopOutput(res.0)
+    }
+}
+
+impl From<MillerLoopOutput<Bls12>> for MillerLoopResult {
+    fn from(out: MillerLoopOutput<Bls12>) -> Self {
+        MillerLoopResult(out.0)
+    }
+}
+
+impl Pairing for Bls12 {
+    type BaseField = Fp;
+    type ScalarField = Scalar;
+    type G1 = G1Projective;
+    type G1Affine = G1Affine;
+    type G1Prepared = G1Affine;
+    type G2 = G2Projective;
+    type G2Affine = G2Affine;
+    type G2Prepared = G2Prepared;
+    type TargetField = Fp12;
+
+    /// Uses [`MultiMillerLoop::multi_miller_loop`]. Extra elements of `a` or `b` are ignored.
+    fn multi_miller_loop(
+        a: impl IntoIterator<Item = impl Into<G1Affine>>,
+        b: impl IntoIterator<Item = impl Into<G2Prepared>>,
+    ) -> MillerLoopOutput<Self> {
+        let a: Vec<G1Affine> = a.into_iter().map(Into::into).collect();
+        let b: Vec<G2Prepared> = b.into_iter().map(Into::into).collect();
+        let terms: Vec<(&G1Affine, &G2Prepared)> = a.iter().zip(b.iter()).collect();
+
+        <Bls12 as MultiMillerLoop>::multi_miller_loop(&terms).into()
+    }
+
+    fn final_exponentiation(mlo: MillerLoopOutput<Self>) -> Option<PairingOutput<Self>> {
+        Some(MillerLoopResult::from(mlo).final_exponentiation().into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use ark_ec::Group;
+    use ark_ff::UniformRand;
+
+    use ark_std::test_rng;
+
+    ark_algebra_test_templates::test_pairing!(ark_pairing; crate::Bls12);
+
+    #[test]
+    fn ark_pairing_matches_engine() {
+        let mut rng = test_rng();
+        for _ in 0..10 {
+            let p = G1Affine::rand(&mut rng);
+            let q = G2Affine::rand(&mut rng);
+            assert_eq!(
+                Gt::try_from(Bls12::pairing(p, q)).unwrap(),
+                crate::pairing(&p, &q)
+            );
+        }
+        assert_eq!(
+            Gt::try_from(PairingOutput::<Bls12>::generator()).unwrap(),
+            <Gt as group::Group>::generator()
+        );
+    }
+
+    #[test]
+    fn ark_g2_prepared_serialization() {
+        let mut rng = test_rng();
+        let points = [
+            G2Affine::rand(&mut rng),
+            <G2Affine as ark_ec::AffineRepr>::zero(),
+        ];
+
+        for q in points {
+            let prepared = G2Prepared::from(q);
+            for compress in [Compress::Yes, Compress::No] {
+                let mut serialized = Vec::new();
+                prepared
+                    .serialize_with_mode(&mut serialized, compress)
+                    .unwrap();
+                assert_eq!(serialized.len(), prepared.serialized_size(compress));
+
+                let deserialized =
+                    G2Prepared::deserialize_with_mode(&serialized[..], compress, Validate::Yes)
+                        .unwrap();
+                assert_eq!(deserialized.infinity, prepared.infinity);
+                assert_eq!(deserialized.lines, prepared.lines);
+
+                // Truncated line functions are rejected.
+                assert!(G2Prepared::deserialize_with_mode(
+                    &serialized[..serialized.len() - 1],
+                    compress,
+                    Validate::Yes
+                )
+                .is_err());
+            }
+        }
+
+        let mut serialized = Vec::new();
+        let invalid = G2Prepared {
+            lines: vec![blst_fp6::default(); 3],
+            infinity: false,
+        };
+        invalid.serialize_compressed(&mut serialized).unwrap();
+        assert!(G2Prepared::deserialize_compressed(&serialized[..]).is_err());
+        assert!(G2Prepared::deserialize_compressed_unchecked(&serialized[..]).is_ok());
+    }
+}
@@ -0,0 +1,129 @@
+//! `ark_ff` field traits for [`Scalar`].
+
+use core::{
+    hash::{Hash, Hasher},
+    iter,
+    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Sub, SubAssign},
+    str::FromStr,
+};
+
+use ark_ff::{BigInt, FftField, Field, LegendreSymbol, One, PrimeField, SqrtPrecomputation, Zero};
+use ark_serialize::{
+    buffer_byte_size, CanonicalDeserialize, CanonicalDeserializeWithFlags, CanonicalSerialize,
+    CanonicalSerializeWithFlags, EmptyFlags, Flags, Read, SerializationError, Valid, Validate,
+    Write,
+};
+use ark_std::rand::{distributions::Standard, prelude::Distribution, Rng};
+use blst::{blst_fr_mul, blst_uint64_from_fr};
+use num_bigint::BigUint;
+use zeroize::Zeroize;
+
+use crate::scalar::{
+    Scalar, GENERATOR, MODULUS, R, ROOT_OF_UNITY, S, TRACE_MINUS_ONE_DIV_TWO, ZERO,
+};
+
+impl_ark_prime_field!(
+    Scalar,
+    limbs: 4,
+    bytes: 32,
+    modulus_bit_size: 255,
+    two_adicity: S,
+    sqrt_precomp: Some(SqrtPrecomputation::TonelliShanks {
+        two_adicity: S,
+        quadratic_nonresidue_to_trace: ROOT_OF_UNITY,
+        trace_of_modulus_minus_one_div_two: &TRACE_MINUS_ONE_DIV_TWO,
+    }),
+    mul: blst_fr_mul,
+    to_u64s: blst_uint64_from_fr,
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use ark_ff::BigInteger;
+
+    #[test]
+    fn test_ark_constants() {
+        assert_eq!(<Scalar as Field>::ONE, <Scalar as ff::Field>::one());
+        assert_eq!(
+            <Scalar as FftField>::GENERATOR.pow(<Scalar as PrimeField>::TRACE),
+            <Scalar as FftField>::TWO_ADIC_ROOT_OF_UNITY
+        );
+        assert_eq!(
+            <Scalar as PrimeField>::TRACE_MINUS_ONE_DIV_TWO,
+            <Scalar as PrimeField>::TRACE.divide_by_2_round_down()
+        );
+        assert_eq!(
+            <Scalar as PrimeField>::MODULUS_MINUS_ONE_DIV_TWO,
+            <Scalar as PrimeField>::MODULUS.divide_by_2_round_down()
+        );
+        assert_eq!(
+            <Scalar as PrimeField>::MODULUS_BIT_SIZE,
+            <Scalar as PrimeField>::MODULUS.num_bits()
+        );
+    }
+
+    // `test_field!` does not apply to `Scalar`, whose inherent `legendre` returning an `i8`
+    // shadows `Field::legendre` in the template. `ark_bls12_381::Fr` is covered by the templates
+    // upstream, so check `Scalar` against it instead.
+    #[test]
+    fn ark_scalar_matches_fr() {
+        use ark_bls12_381::Fr;
+        use ark_ff::UniformRand;
+
+        assert_eq!(<Scalar as PrimeField>::MODULUS, Fr::MODULUS);
+        assert_eq!(<Scalar as PrimeField>::TRACE, Fr::TRACE);
+        assert_eq!(<Scalar as FftField>::TWO_ADICITY, Fr::TWO_ADICITY);
+        assert_eq!(
+            Fr::from(<Scalar as FftField>::TWO_ADIC_ROOT_OF_UNITY),
+            Fr::TWO_ADIC_ROOT_OF_UNITY
+        );
+
+        let mut rng = ark_std::test_rng();
+        for _ in 0..1000 {
+            let a = Scalar::rand(&mut rng);
+            let b = Scalar::rand(&mut rng);
+            let (a_ark, b_ark) = (Fr::from(a), Fr::from(b));
+
+            assert_eq!(Fr::from(a + b), a_ark + b_ark);
+            assert_eq!(Fr::from(a - b), a_ark - b_ark);
+            assert_eq!(Fr::from(a * b), a_ark * b_ark);
+            assert_eq!(Fr::from(a / b), a_ark / b_ark);
+            assert_eq!(Fr::from(Field::square(&a)), a_ark.square());
+            assert_eq!(Field::inverse(&a).map(Fr::from), a_ark.inverse());
+            assert_eq!(
+                Fr::from(Field::pow(&a, [b.into_bigint().0[0]])),
+                a_ark.pow([b_ark.into_bigint().0[0]])
+            );
+            assert_eq!(Field::legendre(&a), a_ark.legendre());
+            assert_eq!(
+                Field::sqrt(&a).map(|s| Fr::from(s).square()),
+                a_ark.sqrt().map(|s| s.square())
+            );
+            assert_eq!(a.into_bigint(), a_ark.into_bigint());
+            assert_eq!(
+                Fr::from(Scalar::sum_of_products(&[a, b], &[b, a])),
+                Fr::sum_of_products(&[a_ark, b_ark], &[b_ark, a_ark])
+            );
+
+            let mut bytes = Vec::new();
+            a.serialize_compressed(&mut bytes).unwrap();
+            let mut bytes_ark = Vec::new();
+            a_ark.serialize_compressed(&mut bytes_ark).unwrap();
+            assert_eq!(bytes, bytes_ark);
+            assert_eq!(Scalar::deserialize_compressed(&bytes[..]).unwrap(), a);
+
+            let mut wide = bytes.clone();
+            wide.extend_from_slice(&bytes);
+            assert_eq!(
+                Fr::from(Scalar::from_le_bytes_mod_order(&wide)),
+                Fr::from_le_bytes_mod_order(&wide)
+            );
+            assert_eq!(
+                Scalar::from_random_bytes(&wide).map(Fr::from),
+                Fr::from_random_bytes(&wide)
+            );
+        }
+    }
+}
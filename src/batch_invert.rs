@@ -0,0 +1,126 @@
+//! Batch inversion of field elements with Montgomery's trick.
+
+use ff::Field;
+
+/// The smallest number of elements given to each thread by [`batch_invert_parallel`].
+#[cfg(feature = "parallel")]
+const MIN_PARALLEL_CHUNK: usize = 1 << 10;
+
+/// Inverts all the elements of `v` with a single inversion, in constant time. Zeros are left as
+/// zero.
+pub(crate) fn batch_invert<F: Field>(v: &mut [F]) {
+    // The zeros are skipped in the running products by multiplying by one instead.
+    let mut products = Vec::with_capacity(v.len());
+    let mut acc = F::one();
+    for x in v.iter() {
+        products.push(acc);
+        acc = F::conditional_select(&(acc * x), &acc, x.is_zero());
+    }
+
+    // `acc` is a product of non-zero elements, so it is invertible.
+    acc = acc.invert().unwrap();
+
+    for (x, product) in v.iter_mut().zip(products).rev() {
+        let is_zero = x.is_zero();
+        let inverse = acc * product;
+        acc = F::conditional_select(&(acc * *x), &acc, is_zero);
+        *x = F::conditional_select(&inverse, &F::zero(), is_zero);
+    }
+}
+
+/// Like [`batch_invert`], but splits `v` across the threads of the current `rayon` pool, with
+/// one inversion per thread.
+#[cfg(feature = "parallel")]
+pub(crate) fn batch_invert_parallel<F: Field>(v: &mut [F]) {
+    use rayon::prelude::*;
+
+    let threads = rayon::current_num_threads();
+    let chunk_size = ((v.len() + threads - 1) / threads).max(MIN_PARALLEL_CHUNK);
+    v.par_chunks_mut(chunk_size).for_each(batch_invert);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use rand_core::SeedableRng;
+    use rand_xorshift::XorShiftRng;
+
+    use crate::{Fp, Fp2, Scalar};
+
+    fn batch_invert_test<F: Field>() {
+        let mut rng = XorShiftRng::from_seed([
+            0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06,
+            0xbc, 0xe5,
+        ]);
+
+        for size in [0, 1, 2, 10, 100] {
+            let v: Vec<F> = (0..size)
+                .map(|i| {
+                    if i % 7 == 3 {
+                        F::zero()
+                    } else {
+                        F::random(&mut rng)
+                    }
+                })
+                .collect();
+            let expected: Vec<F> = v.iter().map(|x| x.invert().unwrap_or(F::zero())).collect();
+
+            let mut inverted = v.clone();
+            batch_invert(&mut inverted);
+            assert_eq!(inverted, expected, "size = {}", size);
+
+            #[cfg(feature = "parallel")]
+            {
+                let mut inverted = v.clone();
+                batch_invert_parallel(&mut inverted);
+                assert_eq!(inverted, expected, "size = {}", size);
+            }
+        }
+
+        let mut zeros = [F::zero(); 3];
+        batch_invert(&mut zeros);
+        assert_eq!(zeros, [F::zero(); 3]);
+    }
+
+    #[test]
+    fn test_scalar_batch_invert() {
+        batch_invert_test::<Scalar>();
+    }
+
+    #[test]
+    fn test_fp_batch_invert() {
+        batch_invert_test::<Fp>();
+    }
+
+    #[test]
+    fn test_fp2_batch_invert() {
+        batch_invert_test::<Fp2>();
+    }
+
+    #[cfg(feature = "parallel")]
+    #[test]
+    fn test_batch_invert_parallel_chunks() {
+        let mut rng = XorShiftRng::from_seed([
+            0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06,
+            0xbc, 0xe5,
+        ]);
+        let pool = rayon::ThreadPoolBuilder::new()
+            .num_threads(3)
+            .build()
+            .unwrap();
+
+        // Above `3 * MIN_PARALLEL_CHUNK` elements, so that it is split in three chunks.
+        let mut v: Vec<Scalar> = (0..3 * MIN_PARALLEL_CHUNK + 5)
+            .map(|_| Scalar::random(&mut rng))
+            .collect();
+        v[MIN_PARALLEL_CHUNK] = Scalar::zero();
+        let expected: Vec<Scalar> = v
+            .iter()
+            .map(|x| x.invert().unwrap_or(Scalar::zero()))
+            .collect();
+
+        pool.install(|| Scalar::batch_invert_parallel(&mut v));
+        assert_eq!(v, expected);
+    }
+}
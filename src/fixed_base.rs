@@ -0,0 +1,117 @@
+//! Scalar multiplication of a fixed base point with a precomputed table.
+
+use core::convert::TryInto;
+
+use group::Curve;
+use subtle::{ConditionallySelectable, ConstantTimeEq};
+
+use crate::Scalar;
+
+/// The number of bits of each window of the scalars.
+const WINDOW_BITS: usize = 4;
+/// The number of windows of a 256-bit scalar.
+const NUM_WINDOWS: usize = 256 / WINDOW_BITS;
+/// The number of multiples of the base point per window.
+const WINDOW_SIZE: usize = 1 << WINDOW_BITS;
+
+/// A table of multiples of a fixed base point, for fast constant-time multiplications of that
+/// point, such as [`G1Projective::mul_by_generator`](crate::G1Projective::mul_by_generator).
+///
+/// For each 4-bit window of the scalar, the table holds the 16 multiples of the base point that
+/// the window can select, so that a multiplication is 64 mixed additions and no doublings. The
+/// table holds 1024 affine points.
+#[derive(Clone, Debug)]
+pub struct FixedBaseTable<G: Curve> {
+    /// `windows[i][j] = j * 16^i * base`.
+    windows: Vec<[G::AffineRepr; WINDOW_SIZE]>,
+}
+
+impl<G> FixedBaseTable<G>
+where
+    G: Curve<Scalar = Scalar>,
+    G::AffineRepr: ConditionallySelectable,
+{
+    /// Builds the table of multiples of `base`.
+    pub fn new(base: &G) -> Self {
+        let mut points = Vec::with_capacity(NUM_WINDOWS * WINDOW_SIZE);
+        let mut window_base = *base;
+        for _ in 0..NUM_WINDOWS {
+            let mut multiple = G::identity();
+            for _ in 0..WINDOW_SIZE {
+                points.push(multiple);
+                multiple += window_base;
+            }
+            window_base = multiple;
+        }
+
+        let mut affine = vec![G::identity().to_affine(); points.len()];
+        G::batch_normalize(&points, &mut affine);
+        let windows = affine
+            .chunks(WINDOW_SIZE)
+            .map(|window| window.try_into().unwrap())
+            .collect();
+
+        FixedBaseTable { windows }
+    }
+
+    /// Computes `scalar * base` in constant time.
+    pub fn mul(&self, scalar: &Scalar) -> G {
+        let bytes = scalar.to_bytes_le();
+
+        let mut acc = G::identity();
+        for (i, window) in self.windows.iter().enumerate() {
+            let digit = (bytes[i / 2] >> (WINDOW_BITS * (i % 2))) & 0xf;
+            let mut term = window[0];
+            for (j, entry) in window.iter().enumerate().skip(1) {
+                term.conditional_assign(entry, (j as u8).ct_eq(&digit));
+            }
+            acc += &term;
+        }
+
+        acc
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use ff::Field;
+    use rand_core::SeedableRng;
+    use rand_xorshift::XorShiftRng;
+
+    use crate::{G1Projective, G2Projective};
+
+    fn fixed_base_table_test<G>()
+    where
+        G: Curve<Scalar = Scalar>,
+        G::AffineRepr: ConditionallySelectable,
+    {
+        let mut rng = XorShiftRng::from_seed([
+            0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06,
+            0xbc, 0xe5,
+        ]);
+
+        let scalars: Vec<Scalar> = (0..10)
+            .map(|_| Scalar::random(&mut rng))
+            .chain([Scalar::zero(), Scalar::one(), -Scalar::one()])
+            .collect();
+
+        for base in [G::random(&mut rng), G::identity(), G::generator()] {
+            let table = FixedBaseTable::new(&base);
+            for s in &scalars {
+                assert_eq!(table.mul(s), base * s);
+            }
+        }
+    }
+
+    #[test]
+    fn test_g1_fixed_base_table() {
+        fixed_base_table_test::<G1Projective>();
+    }
+
+    #[test]
+    fn test_g2_fixed_base_table() {
+        fixed_base_table_test::<G2Projective>();
+    }
+}
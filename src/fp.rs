@@ -4,18 +4,22 @@
 use blst::*;
 
 use core::{
-    cmp, fmt,
+    borrow::Borrow,
+    cmp,
+    convert::TryInto,
+    fmt,
+    iter::{Product, Sum},
     ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign},
 };
-use ff::Field;
+use ff::{Field, PrimeField};
 use rand_core::RngCore;
 use subtle::{Choice, ConditionallySelectable, ConstantTimeEq, CtOption};
 
-use crate::fp2::Fp2;
+use crate::{fp2::Fp2, hash_to_field::ExpandMsg};
 
 // Little-endian non-Montgomery form.
 #[allow(dead_code)]
-const MODULUS: [u64; 6] = [
+pub(crate) const MODULUS: [u64; 6] = [
     0xb9fe_ffff_ffff_aaab,
     0x1eab_fffe_b153_ffff,
     0x6730_d2a0_f6b0_f624,
@@ -60,6 +64,44 @@ const R2: Fp = Fp(blst_fp {
     ],
 });
 
+/// R3 = 2^(384*3) mod p
+const R3: Fp = Fp(blst_fp {
+    l: [
+        0xed48_ac6b_d94c_a1e0,
+        0x315f_831e_03a7_adf8,
+        0x9a53_352a_615e_29dd,
+        0x34c0_4e5e_921e_1761,
+        0x2512_d435_6572_4728,
+        0x0aa6_3460_9175_5d4d,
+    ],
+});
+
+/// `2`, a generator of the multiplicative group, in little-endian Montgomery form.
+pub(crate) const GENERATOR: Fp = Fp(blst_fp {
+    l: [
+        0x3213_0000_0006_554f,
+        0xb93c_0018_d6c4_0005,
+        0x5760_5e0d_b0dd_bb51,
+        0x8b25_6521_ed1f_9bcb,
+        0x6cf2_8d79_0162_2c03,
+        0x11eb_ab9d_bb81_e28c,
+    ],
+});
+
+/// `-1`, the `2^1` root of unity, in little-endian Montgomery form.
+pub(crate) const ROOT_OF_UNITY: Fp = Fp(blst_fp {
+    l: [
+        0x43f5_ffff_fffc_aaae,
+        0x32b7_fff2_ed47_fffd,
+        0x07e8_3a49_a2e9_9d69,
+        0xeca8_f331_8332_bb7a,
+        0xef14_8d1e_a0f4_c069,
+        0x040a_b326_3eff_0206,
+    ],
+});
+
+/// An element of the BLS12-381 base field `GF(p)`.
+///
 /// `Fp` values are always in Montgomery form; i.e., Fp(a) = aR mod p, with R = 2^384. `blst_fp.l`
 /// is in little-endian `u64` limbs format.
 #[derive(Copy, Clone)]
@@ -465,6 +507,30 @@ impl MulAssign<&Fp> for Fp {
     }
 }
 
+impl<T> Sum<T> for Fp
+where
+    T: Borrow<Fp>,
+{
+    fn sum<I>(iter: I) -> Self
+    where
+        I: Iterator<Item = T>,
+    {
+        iter.fold(Fp::zero(), |sum, x| sum + x.borrow())
+    }
+}
+
+impl<T> Product<T> for Fp
+where
+    T: Borrow<Fp>,
+{
+    fn product<I>(iter: I) -> Self
+    where
+        I: Iterator<Item = T>,
+    {
+        iter.fold(Fp::one(), |product, x| product * x.borrow())
+    }
+}
+
 impl_add_sub!(Fp);
 impl_add_sub_assign!(Fp);
 impl_mul!(Fp);
@@ -502,6 +568,10 @@ const NUM_BITS: u32 = 381;
 /// The number of bits we should "shave" from a randomly sampled reputation.
 const REPR_SHAVE_BITS: usize = 384 - NUM_BITS as usize;
 
+/// The number of uniformly random bytes reduced to each field element by `hash_to_field`, `L` in
+/// RFC 9380.
+const HASH_TO_FIELD_LEN: usize = 64;
+
 impl Field for Fp {
     fn random(mut rng: impl RngCore) -> Self {
         loop {
@@ -558,13 +628,90 @@ impl Field for Fp {
     }
 }
 
+/// The canonical little-endian byte representation of an [`Fp`], used as
+/// [`PrimeField::Repr`].
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub struct FpRepr(pub [u8; 48]);
+
+impl Default for FpRepr {
+    fn default() -> Self {
+        FpRepr([0u8; 48])
+    }
+}
+
+impl fmt::Debug for FpRepr {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "0x")?;
+        for b in self.0.iter().rev() {
+            write!(f, "{:02x}", b)?;
+        }
+        Ok(())
+    }
+}
+
+impl AsRef<[u8]> for FpRepr {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl AsMut<[u8]> for FpRepr {
+    fn as_mut(&mut self) -> &mut [u8] {
+        &mut self.0
+    }
+}
+
+impl PrimeField for Fp {
+    // Little-endian non-Montgomery form bigint mod p.
+    type Repr = FpRepr;
+
+    const NUM_BITS: u32 = NUM_BITS;
+    const CAPACITY: u32 = NUM_BITS - 1;
+    const S: u32 = 1;
+
+    /// Converts a little-endian non-Montgomery form `repr` into a Montgomery form `Fp`.
+    fn from_repr(repr: Self::Repr) -> CtOption<Self> {
+        Self::from_bytes_le(&repr.0)
+    }
+
+    /// Converts a Montgomery form `Fp` into little-endian non-Montgomery from.
+    fn to_repr(&self) -> Self::Repr {
+        FpRepr(self.to_bytes_le())
+    }
+
+    fn is_odd(&self) -> Choice {
+        Choice::from(self.to_bytes_le()[0] & 1)
+    }
+
+    fn multiplicative_generator() -> Self {
+        GENERATOR
+    }
+
+    fn root_of_unity() -> Self {
+        ROOT_OF_UNITY
+    }
+}
+
 impl Fp {
+    /// Inverts all the elements of `v` in place with Montgomery's trick, which costs a single
+    /// inversion, in constant time. Zeros are left as zero.
+    pub fn batch_invert(v: &mut [Self]) {
+        crate::batch_invert::batch_invert(v)
+    }
+
+    /// Like [`Fp::batch_invert`], but splits `v` across the threads of the current `rayon` pool.
+    #[cfg(feature = "parallel")]
+    pub fn batch_invert_parallel(v: &mut [Self]) {
+        crate::batch_invert::batch_invert_parallel(v)
+    }
+
+    /// Returns the modulus `p` in little-endian byte order.
     pub fn char() -> [u8; 48] {
         MODULUS_REPR
     }
 
     /// Attempts to convert a little-endian byte representation of
-    /// a scalar into an `Fp`, failing if the input is not canonical.
+    /// an integer into an `Fp`, failing if the input is not canonical.
     pub fn from_bytes_le(bytes: &[u8; 48]) -> CtOption<Fp> {
         // TODO: constant time
         let is_some = Choice::from(is_valid(bytes) as u8);
@@ -575,7 +722,7 @@ impl Fp {
     }
 
     /// Attempts to convert a big-endian byte representation of
-    /// a scalar into an `Fp`, failing if the input is not canonical.
+    /// an integer into an `Fp`, failing if the input is not canonical.
     pub fn from_bytes_be(be_bytes: &[u8; 48]) -> CtOption<Fp> {
         let mut le_bytes = *be_bytes;
         le_bytes.reverse();
@@ -598,6 +745,32 @@ impl Fp {
         bytes
     }
 
+    /// The `hash_to_field` function of RFC 9380, hashing `msg` to `count` field elements with the
+    /// `expand_message` function `X` and the domain separation tag `dst`.
+    pub(crate) fn hash_to_field<X: ExpandMsg>(msg: &[u8], dst: &[u8], count: usize) -> Vec<Fp> {
+        X::expand_message(msg, dst, count * HASH_TO_FIELD_LEN)
+            .chunks(HASH_TO_FIELD_LEN)
+            .map(Fp::from_uniform_bytes)
+            .collect()
+    }
+
+    /// Reduces `HASH_TO_FIELD_LEN` big-endian bytes modulo `p`.
+    fn from_uniform_bytes(bytes: &[u8]) -> Fp {
+        // Split the input into `hi * 2^384 + lo`. Montgomery multiplication of the raw limbs by
+        // `R^2` and `R^3` yields `lo` and `hi * 2^384` in Montgomery form.
+        let mut lo = [0u64; 6];
+        let mut hi = [0u64; 6];
+        for (i, chunk) in bytes.rchunks(8).enumerate() {
+            let limb = u64::from_be_bytes(chunk.try_into().unwrap());
+            if i < 6 {
+                lo[i] = limb;
+            } else {
+                hi[i - 6] = limb;
+            }
+        }
+        Fp::from_raw_unchecked(lo) * R2 + Fp::from_raw_unchecked(hi) * R3
+    }
+
     /// Constructs an element of `Fp` from a little-endian array of limbs without checking that it
     /// is canonical and without converting it to Montgomery form (i.e. without multiplying by `R`).
     pub fn from_raw_unchecked(l: [u64; 6]) -> Fp {
@@ -625,7 +798,8 @@ impl Fp {
         out
     }
 
-    // `u64s` represent a little-endian non-Montgomery form integer mod p.
+    /// Attempts to convert a little-endian non-Montgomery form array of limbs into an `Fp`,
+    /// failing if the input is not canonical.
     pub fn from_u64s_le(bytes: &[u64; 6]) -> CtOption<Self> {
         let is_some = Choice::from(is_valid_u64(bytes) as u8);
         let mut out = blst_fp::default();
@@ -633,6 +807,7 @@ impl Fp {
         CtOption::new(Fp(out), is_some)
     }
 
+    /// Returns the number of bits needed to represent `self` in non-Montgomery form.
     pub fn num_bits(&self) -> u32 {
         let mut ret = 384;
         for i in self.to_bytes_be().iter() {
@@ -646,10 +821,12 @@ impl Fp {
         ret
     }
 
+    /// Returns whether `self` is a square in `Fp`.
     pub fn is_quad_res(&self) -> Choice {
         self.sqrt().is_some()
     }
 
+    /// Squares `self` in place.
     #[inline]
     pub fn square_assign(&mut self) {
         unsafe { blst_fp_sqr(&mut self.0, &self.0) };
@@ -705,7 +882,7 @@ mod tests {
 
     #[test]
     fn test_fp_from_u64() {
-        let a = Fp::from(100);
+        let a = Fp::from(100u64);
         let mut expected_bytes = [0u8; 48];
         expected_bytes[0] = 100;
         assert_eq!(a.to_bytes_le(), expected_bytes);
@@ -713,8 +890,8 @@ mod tests {
 
     #[test]
     fn test_fp_is_zero() {
-        assert!(bool::from(Fp::from(0).is_zero()));
-        assert!(!bool::from(Fp::from(1).is_zero()));
+        assert!(bool::from(Fp::from(0u64).is_zero()));
+        assert!(!bool::from(Fp::from(1u64).is_zero()));
         assert!(!bool::from(
             Fp::from_u64s_le(&[0, 0, 0, 0, 1, 0]).unwrap().is_zero()
         ));
@@ -736,7 +913,7 @@ mod tests {
             });
             assert!(!bool::from(tmp.is_zero()));
             // Test that adding zero has no effect.
-            tmp.add_assign(&Fp::from(0));
+            tmp.add_assign(&Fp::from(0u64));
             assert_eq!(
                 tmp,
                 Fp(blst::blst_fp {
@@ -948,8 +1125,8 @@ mod tests {
             );
 
             // Test for sensible results with zero
-            tmp = Fp::from(0);
-            tmp.sub_assign(&Fp::from(0));
+            tmp = Fp::from(0u64);
+            tmp.sub_assign(&Fp::from(0u64));
             assert!(bool::from(tmp.is_zero()));
 
             tmp = Fp(blst::blst_fp {
@@ -962,7 +1139,7 @@ mod tests {
                     0x5968bb602790806,
                 ],
             });
-            tmp.sub_assign(&Fp::from(0));
+            tmp.sub_assign(&Fp::from(0u64));
             assert_eq!(
                 tmp,
                 Fp(blst::blst_fp {
@@ -1190,7 +1367,7 @@ mod tests {
             // Exponentiate by various small numbers and ensure it consists with repeated
             // multiplication.
             let a = Fp::random(&mut rng);
-            let target = a.pow_vartime(&[i]);
+            let target = a.pow_vartime([i]);
             let mut c = Fp::one();
             for _ in 0..i {
                 c.mul_assign(&a);
@@ -1303,7 +1480,7 @@ mod tests {
         assert_eq!(a, c);
 
         // Zero should be in the field.
-        assert!(bool::from(Fp::from(0).is_zero()));
+        assert!(bool::from(Fp::from(0u64).is_zero()));
 
         let mut rng = XorShiftRng::from_seed([
             0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06,
@@ -1354,9 +1531,9 @@ mod tests {
     fn test_fp_num_bits() {
         assert_eq!(NUM_BITS, 381);
 
-        let mut a = Fp::from(0);
+        let mut a = Fp::from(0u64);
         assert_eq!(0, a.num_bits());
-        a = Fp::from(1);
+        a = Fp::from(1u64);
         assert_eq!(1, a.num_bits());
         for i in 2..NUM_BITS {
             a = a.shl(1);
@@ -1368,13 +1545,39 @@ mod tests {
     fn fp_field_tests() {
         crate::tests::field::random_field_tests::<Fp>();
         crate::tests::field::random_sqrt_tests::<Fp>();
+        crate::tests::field::from_str_tests::<Fp>();
+    }
+
+    #[test]
+    fn test_fp_repr_conversion() {
+        let a = Fp::from(12u64);
+        let mut expected_bytes = FpRepr::default();
+        expected_bytes.0[0] = 12;
+        assert_eq!(a, Fp::from_repr(a.to_repr()).unwrap());
+        assert_eq!(a.to_repr(), expected_bytes);
+        assert_eq!(a, Fp::from_repr_vartime(expected_bytes).unwrap());
+        assert!(bool::from(a.is_even()));
+        assert!(bool::from(Fp::from(13u64).is_odd()));
+
+        // The modulus is not canonical.
+        assert!(bool::from(Fp::from_repr(FpRepr(Fp::char())).is_none()));
+        assert_eq!((-Fp::one()).to_repr().0[1..], FpRepr(Fp::char()).0[1..],);
+    }
+
+    #[test]
+    fn test_fp_root_of_unity() {
+        assert_eq!(Fp::S, 1);
+        assert_eq!(Fp::multiplicative_generator(), Fp::from(2u64));
+        assert!(!bool::from(Fp::multiplicative_generator().is_quad_res()));
+        assert_eq!(Fp::root_of_unity(), -Fp::one());
+        assert_eq!(Fp::root_of_unity().square(), Fp::one());
     }
 
     #[test]
     fn test_fp_ordering() {
         // FpRepr's ordering is well-tested, but we still need to make sure the Fp
         // elements aren't being compared in Montgomery form.
-        for i in 0..100 {
+        for i in 0..100u64 {
             let a = Fp::from(i + 1);
             let b = Fp::from(i);
             assert!(a > b, "{}: {:?} > {:?}", i, a, b);
@@ -1403,4 +1606,36 @@ mod tests {
         assert_eq!(a.invert().unwrap(), b);
         assert!(bool::from(Fp::zero().invert().is_none()));
     }
+
+    // Test vectors from RFC 9380, Appendix J.9.1.
+    #[test]
+    fn test_hash_to_field() {
+        use crate::hash_to_field::ExpandMsgXmdSha256;
+
+        const DST: &[u8] = b"QUUX-V01-CS02-with-BLS12381G1_XMD:SHA-256_SSWU_RO_";
+
+        let fp = |s: &str| Fp::from_bytes_be(&hex::decode(s).unwrap().try_into().unwrap()).unwrap();
+
+        assert_eq!(
+            Fp::hash_to_field::<ExpandMsgXmdSha256>(b"", DST, 2),
+            [
+                fp("0ba14bd907ad64a016293ee7c2d276b8eae71f25a4b941eece7b0d89f17f75cb3ae5438a614fb61d6835ad59f29c564f"),
+                fp("019b9bd7979f12657976de2884c7cce192b82c177c80e0ec604436a7f538d231552f0d96d9f7babe5fa3b19b3ff25ac9"),
+            ]
+        );
+        assert_eq!(
+            Fp::hash_to_field::<ExpandMsgXmdSha256>(b"abc", DST, 2),
+            [
+                fp("0d921c33f2bad966478a03ca35d05719bdf92d347557ea166e5bba579eea9b83e9afa5c088573c2281410369fbd32951"),
+                fp("003574a00b109ada2f26a37a91f9d1e740dffd8d69ec0c35e1e9f4652c7dba61123e9dd2e76c655d956e2b3462611139"),
+            ]
+        );
+        assert!(Fp::hash_to_field::<ExpandMsgXmdSha256>(b"abc", DST, 0).is_empty());
+
+        // (2^512 - 1) mod p
+        assert_eq!(
+            Fp::from_uniform_bytes(&[0xff; HASH_TO_FIELD_LEN]),
+            fp("02cb5d3a884e56c4fab7cd07ee4e16bc15efebb5d396d7cf82383087033108464532383fa8eaff4e967d3988a62b6c9c")
+        );
+    }
 }
@@ -3,7 +3,9 @@
 use blst::*;
 
 use core::{
+    borrow::Borrow,
     fmt,
+    iter::{Product, Sum},
     ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign},
 };
 
@@ -51,6 +53,12 @@ impl From<Fp6> for Fp12 {
     }
 }
 
+impl From<u64> for Fp12 {
+    fn from(val: u64) -> Fp12 {
+        Fp12::from(Fp::from(val))
+    }
+}
+
 impl From<blst_fp12> for Fp12 {
     fn from(val: blst_fp12) -> Fp12 {
         Fp12(val)
@@ -203,6 +211,30 @@ impl MulAssign<&Fp12> for Fp12 {
     }
 }
 
+impl<T> Sum<T> for Fp12
+where
+    T: Borrow<Fp12>,
+{
+    fn sum<I>(iter: I) -> Self
+    where
+        I: Iterator<Item = T>,
+    {
+        iter.fold(Fp12::zero(), |sum, x| sum + x.borrow())
+    }
+}
+
+impl<T> Product<T> for Fp12
+where
+    T: Borrow<Fp12>,
+{
+    fn product<I>(iter: I) -> Self
+    where
+        I: Iterator<Item = T>,
+    {
+        iter.fold(Fp12::one(), |product, x| product * x.borrow())
+    }
+}
+
 impl_add_sub!(Fp12);
 impl_add_sub_assign!(Fp12);
 impl_mul!(Fp12);
@@ -255,6 +287,7 @@ impl Fp12 {
         Fp12(blst_fp12 { fp6: [c0.0, c1.0] })
     }
 
+    /// Raises `self` to the `p^power`-th power in place.
     pub fn frobenius_map(&mut self, power: usize) {
         if power > 0 && power < 4 {
             unsafe { blst_fp12_frobenius_map(&mut self.0, &self.0, power) }
@@ -290,14 +323,18 @@ impl Fp12 {
         }
     }
 
+    /// Returns the coefficient `c0`.
     pub fn c0(&self) -> Fp6 {
         Fp6(self.0.fp6[0])
     }
 
+    /// Returns the coefficient `c1`.
     pub fn c1(&self) -> Fp6 {
         Fp6(self.0.fp6[1])
     }
 
+    /// Replaces `self` with its conjugate `c0 - c1 w`, which is the inverse of elements of the
+    /// cyclotomic subgroup, such as [`Gt`](crate::Gt) elements.
     pub fn conjugate(&mut self) {
         unsafe { blst_fp12_conjugate(&mut self.0) };
     }
@@ -311,7 +348,7 @@ impl ec_gpu::GpuName for Fp12 {
 }
 
 // non_residue^((modulus^i-1)/6) for i=0,...,11
-const FROBENIUS_COEFF_FP12_C1: [blst_fp2; 12] = [
+pub(crate) const FROBENIUS_COEFF_FP12_C1: [blst_fp2; 12] = [
     // Fp2(u + 1)**(((q^0) - 1) / 6)
     blst_fp2 {
         fp: [
@@ -3,16 +3,22 @@
 use blst::*;
 
 use core::{
+    borrow::Borrow,
     cmp::{Ord, Ordering, PartialOrd},
     fmt,
+    iter::{Product, Sum},
     ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign},
 };
 use ff::Field;
 use rand_core::RngCore;
 use subtle::{Choice, ConditionallySelectable, ConstantTimeEq, CtOption};
 
-use crate::fp::{Fp, FROBENIUS_COEFF_FP2_C1};
+use crate::{
+    fp::{Fp, FROBENIUS_COEFF_FP2_C1},
+    hash_to_field::ExpandMsg,
+};
 
+/// This represents an element $c_0 + c_1 u$ of $\mathbb{F}_{p^2} = \mathbb{F}_p / u^2 + 1$.
 #[derive(Copy, Clone)]
 #[repr(transparent)]
 pub struct Fp2(pub(crate) blst_fp2);
@@ -180,12 +186,48 @@ impl MulAssign<&Fp2> for Fp2 {
     }
 }
 
+impl<T> Sum<T> for Fp2
+where
+    T: Borrow<Fp2>,
+{
+    fn sum<I>(iter: I) -> Self
+    where
+        I: Iterator<Item = T>,
+    {
+        iter.fold(Fp2::zero(), |sum, x| sum + x.borrow())
+    }
+}
+
+impl<T> Product<T> for Fp2
+where
+    T: Borrow<Fp2>,
+{
+    fn product<I>(iter: I) -> Self
+    where
+        I: Iterator<Item = T>,
+    {
+        iter.fold(Fp2::one(), |product, x| product * x.borrow())
+    }
+}
+
 impl_add_sub!(Fp2);
 impl_add_sub_assign!(Fp2);
 impl_mul!(Fp2);
 impl_mul_assign!(Fp2);
 
 impl Fp2 {
+    /// Inverts all the elements of `v` in place with Montgomery's trick, which costs a single
+    /// inversion, in constant time. Zeros are left as zero.
+    pub fn batch_invert(v: &mut [Self]) {
+        crate::batch_invert::batch_invert(v)
+    }
+
+    /// Like [`Fp2::batch_invert`], but splits `v` across the threads of the current `rayon` pool.
+    #[cfg(feature = "parallel")]
+    pub fn batch_invert_parallel(v: &mut [Self]) {
+        crate::batch_invert::batch_invert_parallel(v)
+    }
+
     /// Constructs an element of `Fp2`.
     pub const fn new(c0: Fp, c1: Fp) -> Fp2 {
         Fp2(blst_fp2 { fp: [c0.0, c1.0] })
@@ -212,10 +254,12 @@ impl Fp2 {
         out
     }
 
+    /// Returns the coefficient `c0`.
     pub fn c0(&self) -> Fp {
         Fp(self.0.fp[0])
     }
 
+    /// Returns the coefficient `c1`.
     pub fn c1(&self) -> Fp {
         Fp(self.0.fp[1])
     }
@@ -230,20 +274,31 @@ impl Fp2 {
         self.0.fp[1] = c1.0;
     }
 
-    /// Norm of Fq2 as extension field in i over Fq
+    /// Returns the norm `c0^2 + c1^2` of `self` over `Fp`.
     pub fn norm(&self) -> Fp {
         self.c0().square() + self.c1().square()
     }
 
+    /// Raises `self` to the `p^power`-th power in place.
     pub fn frobenius_map(&mut self, power: usize) {
         let mut c1 = self.c1();
         c1 *= &FROBENIUS_COEFF_FP2_C1[power % 2];
         self.0.fp[1] = c1.0;
     }
 
+    /// Returns whether `self` is a square in `Fp2`.
     pub fn is_quad_res(&self) -> bool {
         self.sqrt().is_some().into()
     }
+
+    /// The `hash_to_field` function of RFC 9380, hashing `msg` to `count` elements of `Fp2` with
+    /// the `expand_message` function `X` and the domain separation tag `dst`.
+    pub(crate) fn hash_to_field<X: ExpandMsg>(msg: &[u8], dst: &[u8], count: usize) -> Vec<Fp2> {
+        Fp::hash_to_field::<X>(msg, dst, 2 * count)
+            .chunks(2)
+            .map(|c| Fp2::new(c[0], c[1]))
+            .collect()
+    }
 }
 
 impl Field for Fp2 {
@@ -359,7 +414,7 @@ mod tests {
     fn test_fp2_squaring() {
         let a = Fp2::new(Fp::one(), Fp::one()); // u + 1
         let a_sq = a.square();
-        assert_eq!(a_sq, Fp2::new(Fp::zero(), Fp::from(2))); // 2u
+        assert_eq!(a_sq, Fp2::new(Fp::zero(), Fp::from(2u64))); // 2u
 
         let a = Fp2::new(Fp::zero(), Fp::one()); // u
         let a_sq = a.square();
@@ -362,14 +362,17 @@ impl Fp6 {
         })
     }
 
+    /// Returns the coefficient `c0`.
     pub fn c0(&self) -> Fp2 {
         Fp2(self.0.fp2[0])
     }
 
+    /// Returns the coefficient `c1`.
     pub fn c1(&self) -> Fp2 {
         Fp2(self.0.fp2[1])
     }
 
+    /// Returns the coefficient `c2`.
     pub fn c2(&self) -> Fp2 {
         Fp2(self.0.fp2[2])
     }
@@ -384,6 +387,7 @@ impl Fp6 {
         self.0.fp2[0] = c0.0;
     }
 
+    /// Raises `self` to the `p^power`-th power in place.
     pub fn frobenius_map(&mut self, power: usize) {
         let mut c0 = self.c0();
         c0.frobenius_map(power);
@@ -5,6 +5,7 @@ use core::{
     fmt,
     iter::Sum,
     ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+    ptr,
 };
 use std::io::Read;
 
@@ -13,10 +14,38 @@ use group::{
     prime::{PrimeCurve, PrimeCurveAffine, PrimeGroup},
     Curve, Group, GroupEncoding, UncompressedEncoding, WnafGroup,
 };
+use once_cell::sync::OnceCell;
 use rand_core::RngCore;
 use subtle::{Choice, ConditionallySelectable, CtOption};
 
-use crate::{fp::Fp, Bls12, Engine, G2Affine, Gt, PairingCurveAffine, Scalar};
+use crate::{
+    fixed_base::FixedBaseTable,
+    fp::Fp,
+    hash_to_field::ExpandMsg,
+    map_to_curve::{iso_map_g1, map_to_curve_simple_swu_g1},
+    msm::{check_lengths, MsmError},
+    scalar::X_ABS,
+    Bls12, Engine, G2Affine, Gt, PairingCurveAffine, Scalar,
+};
+
+#[cfg(feature = "parallel")]
+use crate::msm::par_multi_exp;
+
+/// A non-trivial cube root of unity in `Fp`, in Montgomery form, such that the endomorphism
+/// `(x, y) -> (βx, y)` acts on $\mathbb{G}_1$ as the multiplication by `-x^2`.
+const BETA: Fp = Fp(blst_fp {
+    l: [
+        0x30f1_361b_798a_64e8,
+        0xf3b8_ddab_7ece_5a2a,
+        0x16a8_ca3a_c615_77f7,
+        0xc26a_2ff8_74fd_029b,
+        0x3636_b766_6070_1c6e,
+        0x051b_a4ab_241b_6160,
+    ],
+});
+
+/// The effective cofactor `h_eff = 1 - x` of RFC 9380 for $\mathbb{G}_1$.
+const H_EFF: u64 = X_ABS + 1;
 
 /// This is an element of $\mathbb{G}_1$ represented in the affine coordinate space.
 /// It is ideal to keep elements in this representation to reduce memory usage and
@@ -25,8 +54,8 @@ use crate::{fp::Fp, Bls12, Engine, G2Affine, Gt, PairingCurveAffine, Scalar};
 #[repr(transparent)]
 pub struct G1Affine(pub(crate) blst_p1_affine);
 
-const COMPRESSED_SIZE: usize = 48;
-const UNCOMPRESSED_SIZE: usize = 96;
+pub(crate) const COMPRESSED_SIZE: usize = 48;
+pub(crate) const UNCOMPRESSED_SIZE: usize = 96;
 
 impl fmt::Debug for G1Affine {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
@@ -190,6 +219,24 @@ impl Sub<&G1Affine> for &G1Projective {
     }
 }
 
+impl Add<&G1Affine> for &G1Affine {
+    type Output = G1Projective;
+
+    #[inline]
+    fn add(self, rhs: &G1Affine) -> G1Projective {
+        G1Projective::from(self).add_mixed(rhs)
+    }
+}
+
+impl Sub<&G1Affine> for &G1Affine {
+    type Output = G1Projective;
+
+    #[inline]
+    fn sub(self, rhs: &G1Affine) -> G1Projective {
+        self + (-rhs)
+    }
+}
+
 impl AddAssign<&G1Projective> for G1Projective {
     #[inline]
     fn add_assign(&mut self, rhs: &G1Projective) {
@@ -251,6 +298,7 @@ impl MulAssign<&Scalar> for G1Affine {
 impl_add_sub!(G1Projective);
 impl_add_sub!(G1Projective, G1Affine);
 impl_add_sub!(G1Affine, G1Projective, G1Projective);
+impl_add_sub!(G1Affine, G1Affine, G1Projective);
 
 impl_add_sub_assign!(G1Projective);
 impl_add_sub_assign!(G1Projective, G1Affine);
@@ -273,6 +321,24 @@ where
     }
 }
 
+impl Sum<G1Affine> for G1Projective {
+    fn sum<I>(iter: I) -> Self
+    where
+        I: Iterator<Item = G1Affine>,
+    {
+        iter.fold(Self::identity(), |acc, item| acc + item)
+    }
+}
+
+impl<'a> Sum<&'a G1Affine> for G1Projective {
+    fn sum<I>(iter: I) -> Self
+    where
+        I: Iterator<Item = &'a G1Affine>,
+    {
+        iter.fold(Self::identity(), |acc, item| acc + item)
+    }
+}
+
 impl ConditionallySelectable for G1Affine {
     fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
         G1Affine(blst_p1_affine {
@@ -292,6 +358,46 @@ impl ConditionallySelectable for G1Projective {
     }
 }
 
+/// Multi-exponentiation of `points` by the `nbits`-bit scalars in `scalar_bytes`, which holds
+/// one little-endian scalar of `(nbits + 7) / 8` bytes per point, using `blst`'s implementation
+/// of Pippenger's algorithm on the calling thread.
+pub(crate) fn p1s_mult_pippenger(
+    points: &[G1Affine],
+    scalar_bytes: &[u8],
+    nbits: usize,
+) -> G1Projective {
+    let n = points.len();
+    assert!(scalar_bytes.len() >= n * ((nbits + 7) / 8));
+
+    let mut out = blst_p1::default();
+    match n {
+        0 => {}
+        // blst's Pippenger implementation gives wrong results for a single point.
+        1 => unsafe {
+            let mut point = blst_p1::default();
+            blst_p1_from_affine(&mut point, &points[0].0);
+            blst_p1_mult(&mut out, &point, scalar_bytes.as_ptr(), nbits);
+        },
+        _ => {
+            let mut scratch = vec![0u64; unsafe { blst_p1s_mult_pippenger_scratch_sizeof(n) } / 8];
+            let p: [*const blst_p1_affine; 2] = [&points[0].0, ptr::null()];
+            let s: [*const u8; 2] = [scalar_bytes.as_ptr(), ptr::null()];
+            unsafe {
+                blst_p1s_mult_pippenger(
+                    &mut out,
+                    p.as_ptr(),
+                    n,
+                    s.as_ptr(),
+                    nbits,
+                    scratch.as_mut_ptr(),
+                )
+            };
+        }
+    }
+
+    G1Projective(out)
+}
+
 impl G1Affine {
     /// Serializes this element into compressed form.
     pub fn to_compressed(&self) -> [u8; COMPRESSED_SIZE] {
@@ -351,6 +457,19 @@ impl G1Affine {
         CtOption::new(G1Affine(raw), Choice::from(success as u8))
     }
 
+    /// Attempts to deserialize a batch of compressed elements, returning one result per element.
+    /// Each result matches the one from `from_compressed()`, and the square roots and subgroup
+    /// checks are spread across the threads of the current `rayon` pool.
+    ///
+    /// The subgroup checks are not batched into a random linear combination, which would be
+    /// unsound because the cofactor has small prime factors.
+    #[cfg(feature = "parallel")]
+    pub fn batch_from_compressed(bytes: &[[u8; COMPRESSED_SIZE]]) -> Vec<CtOption<Self>> {
+        use rayon::prelude::*;
+
+        bytes.par_iter().map(G1Affine::from_compressed).collect()
+    }
+
     /// Returns true if this point is free of an $h$-torsion component, and so it
     /// exists within the $q$-order subgroup $\mathbb{G}_1$. This should always return true
     /// unless an "unchecked" API was used.
@@ -381,6 +500,16 @@ impl G1Affine {
         Fp(self.0.y)
     }
 
+    /// Returns the image of this point by the endomorphism `(x, y) -> (βx, y)`, where `β` is a
+    /// non-trivial cube root of unity in `Fp`. On $\mathbb{G}_1$ it is the multiplication by
+    /// `-x^2`, where `x` is the BLS parameter.
+    pub fn endomorphism(&self) -> Self {
+        G1Affine(blst_p1_affine {
+            x: (self.x() * BETA).0,
+            y: self.0.y,
+        })
+    }
+
     pub const fn uncompressed_size() -> usize {
         UNCOMPRESSED_SIZE
     }
@@ -442,6 +571,41 @@ impl G1Affine {
             ))
         }
     }
+
+    /// Perform a multi-exponentiation, aka "multi-scalar-multiplication" (MSM) using `blst`'s implementation of Pippenger's algorithm.
+    /// Unlike [`G1Projective::multi_exp`], the points are passed to `blst` without normalizing
+    /// them first, and the computation runs on the calling thread. Extra points or scalars are
+    /// ignored.
+    pub fn multi_exp(points: &[Self], scalars: &[Scalar]) -> G1Projective {
+        let n = points.len().min(scalars.len());
+
+        let mut scalar_bytes: Vec<u8> = Vec::with_capacity(n * 32);
+        for a in scalars[..n].iter().map(|s| s.to_bytes_le()) {
+            scalar_bytes.extend_from_slice(&a);
+        }
+
+        p1s_mult_pippenger(&points[..n], &scalar_bytes, 255)
+    }
+
+    /// Like [`G1Affine::multi_exp`], but fails if `points` and `scalars` have different lengths,
+    /// or are both empty.
+    pub fn multi_exp_checked(
+        points: &[Self],
+        scalars: &[Scalar],
+    ) -> Result<G1Projective, MsmError> {
+        check_lengths(points.len(), scalars.len())?;
+        Ok(Self::multi_exp(points, scalars))
+    }
+
+    /// Like [`G1Affine::multi_exp`], but splits the points across the threads of the current
+    /// `rayon` pool and sums the partial results. Extra points or scalars are ignored.
+    ///
+    /// The number of threads can be set with `RAYON_NUM_THREADS`, or by calling this inside
+    /// `rayon::ThreadPool::install`.
+    #[cfg(feature = "parallel")]
+    pub fn multi_exp_parallel(points: &[Self], scalars: &[Scalar]) -> G1Projective {
+        par_multi_exp(points, scalars, Self::multi_exp)
+    }
 }
 
 /// This is an element of $\mathbb{G}_1$ represented in the projective coordinate space.
@@ -465,6 +629,12 @@ impl fmt::Display for G1Projective {
     }
 }
 
+impl Default for G1Projective {
+    fn default() -> G1Projective {
+        G1Projective::identity()
+    }
+}
+
 impl AsRef<blst_p1> for G1Projective {
     fn as_ref(&self) -> &blst_p1 {
         &self.0
@@ -624,6 +794,64 @@ impl G1Projective {
         res
     }
 
+    /// Like [`G1Projective::hash_to_curve`], but with the `expand_message` function `X`, such as
+    /// [`ExpandMsgXofShake256`](crate::ExpandMsgXofShake256) for the
+    /// `BLS12381G1_XOF:SHAKE-256_SSWU_RO_` suite.
+    pub fn hash_to_curve_with<X: ExpandMsg>(msg: &[u8], dst: &[u8], aug: &[u8]) -> Self {
+        let u = Fp::hash_to_field::<X>(&[aug, msg].concat(), dst, 2);
+        (Self::map_to_curve(&u[0]) + Self::map_to_curve(&u[1])).clear_cofactor()
+    }
+
+    /// Encode to curve algorithm, the nonuniform encoding of RFC 9380.
+    ///
+    /// This maps a single field element to the curve, so it costs about half as much as
+    /// `hash_to_curve`, but its output is not uniformly distributed. Only use it for protocols
+    /// specified with an `_NU_` suite.
+    pub fn encode_to_curve(msg: &[u8], dst: &[u8], aug: &[u8]) -> Self {
+        let mut res = Self::identity();
+        unsafe {
+            blst_encode_to_g1(
+                &mut res.0,
+                msg.as_ptr(),
+                msg.len(),
+                dst.as_ptr(),
+                dst.len(),
+                aug.as_ptr(),
+                aug.len(),
+            );
+        }
+        res
+    }
+
+    /// The `map_to_curve` function of RFC 9380, the simplified SWU map followed by the
+    /// 11-isogeny. The result is on the curve, but not in $\mathbb{G}_1$ until it is multiplied by
+    /// the cofactor with [`G1Projective::clear_cofactor`].
+    pub fn map_to_curve(u: &Fp) -> Self {
+        let (x, y) = Self::map_to_curve_simple_swu(u);
+        Self::iso_map(&x, &y)
+    }
+
+    /// The simplified SWU map of RFC 9380 to the curve `E': y^2 = x^3 + A' x + B'`, which is
+    /// 11-isogenous to the G1 curve. Returns the affine coordinates of a point of `E'`.
+    pub fn map_to_curve_simple_swu(u: &Fp) -> (Fp, Fp) {
+        map_to_curve_simple_swu_g1(u)
+    }
+
+    /// The 11-isogeny of RFC 9380 from `E'` to the G1 curve, applied to the affine point `(x, y)`
+    /// of `E'`, such as an output of [`G1Projective::map_to_curve_simple_swu`].
+    pub fn iso_map(x: &Fp, y: &Fp) -> Self {
+        let [x, y, z] = iso_map_g1(x, y);
+        G1Projective::from_raw_unchecked(x, y, z)
+    }
+
+    /// Multiplies `self` by the effective cofactor `h_eff = 1 - x` of RFC 9380, which maps any
+    /// point of the curve to $\mathbb{G}_1$.
+    pub fn clear_cofactor(&self) -> Self {
+        let mut out = blst_p1::default();
+        unsafe { blst_p1_mult(&mut out, &self.0, H_EFF.to_le_bytes().as_ptr(), 64) };
+        G1Projective(out)
+    }
+
     /// Perform a multi-exponentiation, aka "multi-scalar-multiplication" (MSM) using `blst`'s implementation of Pippenger's algorithm.
     /// Note: `scalars` is cloned in this method.
     pub fn multi_exp(points: &[Self], scalars: &[Scalar]) -> Self {
@@ -632,19 +860,178 @@ impl G1Projective {
         } else {
             scalars.len()
         };
-        let points =
-            unsafe { std::slice::from_raw_parts(points.as_ptr() as *const blst_p1, points.len()) };
-
-        let points = p1_affines::from(points);
 
         let mut scalar_bytes: Vec<u8> = Vec::with_capacity(n * 32);
-        for a in scalars.iter().map(|s| s.to_bytes_le()) {
+        for a in scalars[..n].iter().map(|s| s.to_bytes_le()) {
+            scalar_bytes.extend_from_slice(&a);
+        }
+
+        Self::multi_exp_bytes(&points[..n], &scalar_bytes, 255)
+    }
+
+    /// Like [`G1Projective::multi_exp`], but only uses the lowest `nbits` bits of each scalar,
+    /// so that `blst` skips the windows of the higher bits. Extra points or scalars are ignored.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `nbits` is not between 1 and 255.
+    pub fn multi_exp_nbits(points: &[Self], scalars: &[Scalar], nbits: usize) -> Self {
+        assert!(
+            (1..=255).contains(&nbits),
+            "nbits must be between 1 and 255"
+        );
+        let n = points.len().min(scalars.len());
+        let nbytes = (nbits + 7) / 8;
+        let mask = 0xff >> (8 * nbytes - nbits);
+
+        let mut scalar_bytes: Vec<u8> = Vec::with_capacity(n * nbytes);
+        for a in scalars[..n].iter().map(|s| s.to_bytes_le()) {
+            scalar_bytes.extend_from_slice(&a[..nbytes]);
+            *scalar_bytes.last_mut().unwrap() &= mask;
+        }
+
+        Self::multi_exp_bytes(&points[..n], &scalar_bytes, nbits)
+    }
+
+    /// Like [`G1Projective::multi_exp`], but for `u64` scalars, using 64-bit windows.
+    pub fn multi_exp_u64(points: &[Self], scalars: &[u64]) -> Self {
+        let n = points.len().min(scalars.len());
+
+        let mut scalar_bytes: Vec<u8> = Vec::with_capacity(n * 8);
+        for a in scalars[..n].iter().map(|s| s.to_le_bytes()) {
             scalar_bytes.extend_from_slice(&a);
         }
 
-        let res = points.mult(scalar_bytes.as_slice(), 255);
+        Self::multi_exp_bytes(&points[..n], &scalar_bytes, 64)
+    }
+
+    /// Like [`G1Projective::multi_exp`], but for scalars that are 0 or 1.
+    pub fn multi_exp_bits(points: &[Self], bits: &[bool]) -> Self {
+        let n = points.len().min(bits.len());
+        let scalar_bytes: Vec<u8> = bits[..n].iter().map(|&b| u8::from(b)).collect();
+
+        Self::multi_exp_bytes(&points[..n], &scalar_bytes, 1)
+    }
+
+    /// Multi-exponentiation of `points` by the `nbits`-bit scalars in `scalar_bytes`, which holds
+    /// one little-endian scalar of `(nbits + 7) / 8` bytes per point.
+    fn multi_exp_bytes(points: &[Self], scalar_bytes: &[u8], nbits: usize) -> Self {
+        // `blst`'s batch conversion to affine coordinates breaks on the point at infinity, which
+        // adds nothing to the sum anyway.
+        let nbytes = (nbits + 7) / 8;
+        let mut bases = Vec::with_capacity(points.len());
+        let mut bytes = Vec::with_capacity(scalar_bytes.len());
+        for (point, scalar) in points.iter().zip(scalar_bytes.chunks(nbytes)) {
+            if !bool::from(point.is_identity()) {
+                bases.push(point.0);
+                bytes.extend_from_slice(scalar);
+            }
+        }
+
+        match bases.len() {
+            0 => Self::identity(),
+            // blst's Pippenger implementation gives wrong results for a single point.
+            1 => {
+                let mut out = blst_p1::default();
+                unsafe { blst_p1_mult(&mut out, &bases[0], bytes.as_ptr(), nbits) };
+                G1Projective(out)
+            }
+            _ => G1Projective(p1_affines::from(&bases).mult(&bytes, nbits)),
+        }
+    }
+
+    /// Like [`G1Projective::multi_exp`], but fails if `points` and `scalars` have different
+    /// lengths, or are both empty.
+    pub fn multi_exp_checked(points: &[Self], scalars: &[Scalar]) -> Result<Self, MsmError> {
+        check_lengths(points.len(), scalars.len())?;
+        Ok(Self::multi_exp(points, scalars))
+    }
+
+    /// Like [`G1Projective::multi_exp`], but splits the points across the threads of the current
+    /// `rayon` pool and sums the partial results. Each thread normalizes its chunk and passes it to
+    /// [`G1Affine::multi_exp`], so `blst` starts no threads of its own. Extra points or scalars
+    /// are ignored.
+    ///
+    /// The number of threads can be set with `RAYON_NUM_THREADS`, or by calling this inside
+    /// `rayon::ThreadPool::install`.
+    #[cfg(feature = "parallel")]
+    pub fn multi_exp_parallel(points: &[Self], scalars: &[Scalar]) -> Self {
+        par_multi_exp(points, scalars, |points, scalars| {
+            let mut affine = vec![G1Affine::identity(); points.len()];
+            Self::batch_normalize(points, &mut affine);
+            G1Affine::multi_exp(&affine, scalars)
+        })
+    }
+
+    /// Returns the image of this point by the endomorphism `(x, y) -> (βx, y)`, see
+    /// [`G1Affine::endomorphism`].
+    pub fn endomorphism(&self) -> Self {
+        G1Projective(blst_p1 {
+            x: (self.x() * BETA).0,
+            y: self.0.y,
+            z: self.0.z,
+        })
+    }
 
-        G1Projective(res)
+    /// Multiplies the generator by `scalar` in constant time, with a [`FixedBaseTable`] of the
+    /// generator that is built on first use.
+    pub fn mul_by_generator(scalar: &Scalar) -> Self {
+        static TABLE: OnceCell<FixedBaseTable<G1Projective>> = OnceCell::new();
+        TABLE
+            .get_or_init(|| FixedBaseTable::new(&Self::generator()))
+            .mul(scalar)
+    }
+
+    /// Performs one multi-exponentiation per entry of `scalars`, all against `bases`, using
+    /// `blst`'s implementation of Pippenger's algorithm. The bases are normalized once, and the
+    /// bucket and scratch memory is shared across the batch. Extra bases or scalars in each entry
+    /// are ignored, so the `i`-th result is `G1Projective::multi_exp(bases, scalars[i])`.
+    ///
+    /// Unlike [`G1Projective::multi_exp`], each multi-exponentiation runs on the calling thread.
+    pub fn batch_multi_exp(bases: &[Self], scalars: &[&[Scalar]]) -> Vec<Self> {
+        let max_n = scalars
+            .iter()
+            .map(|s| s.len().min(bases.len()))
+            .max()
+            .unwrap_or(0);
+        let mut affine = vec![G1Affine::identity(); max_n];
+        Self::batch_normalize(&bases[..max_n], &mut affine);
+
+        let mut scratch = vec![0u64; unsafe { blst_p1s_mult_pippenger_scratch_sizeof(max_n) } / 8];
+        let mut scalar_bytes: Vec<u8> = Vec::with_capacity(max_n * 32);
+        scalars
+            .iter()
+            .map(|scalars| {
+                let n = bases.len().min(scalars.len());
+                match n {
+                    0 => return Self::identity(),
+                    // blst's Pippenger implementation gives wrong results for a single point.
+                    1 => return bases[0] * scalars[0],
+                    _ => {}
+                }
+
+                scalar_bytes.clear();
+                for a in scalars[..n].iter().map(|s| s.to_bytes_le()) {
+                    scalar_bytes.extend_from_slice(&a);
+                }
+
+                let p: [*const blst_p1_affine; 2] = [&affine[0].0, ptr::null()];
+                let s: [*const u8; 2] = [scalar_bytes.as_ptr(), ptr::null()];
+                let mut out = blst_p1::default();
+                unsafe {
+                    blst_p1s_mult_pippenger(
+                        &mut out,
+                        p.as_ptr(),
+                        n,
+                        s.as_ptr(),
+                        255,
+                        scratch.as_mut_ptr(),
+                    )
+                };
+
+                G1Projective(out)
+            })
+            .collect()
     }
 }
 
@@ -652,25 +1039,12 @@ impl Group for G1Projective {
     type Scalar = Scalar;
 
     fn random(mut rng: impl RngCore) -> Self {
-        let mut out = blst_p1::default();
         let mut msg = [0u8; 64];
         rng.fill_bytes(&mut msg);
         const DST: [u8; 16] = [0; 16];
         const AUG: [u8; 16] = [0; 16];
 
-        unsafe {
-            blst_encode_to_g1(
-                &mut out,
-                msg.as_ptr(),
-                msg.len(),
-                DST.as_ptr(),
-                DST.len(),
-                AUG.as_ptr(),
-                AUG.len(),
-            )
-        };
-
-        G1Projective(out)
+        Self::encode_to_curve(&msg, &DST, &AUG)
     }
 
     fn identity() -> Self {
@@ -718,6 +1092,36 @@ impl Curve for G1Projective {
     fn to_affine(&self) -> Self::AffineRepr {
         self.into()
     }
+
+    /// Normalizes all the points with a single inversion, using `blst`'s implementation of
+    /// Montgomery's trick.
+    fn batch_normalize(p: &[Self], q: &mut [Self::AffineRepr]) {
+        assert_eq!(p.len(), q.len());
+        if p.is_empty() {
+            return;
+        }
+
+        // `blst` gives wrong results for all the points if any of them is the identity, so the
+        // identities are replaced by the generator, and restored afterwards.
+        let generator = Self::generator();
+        let points: Vec<Self> = p
+            .iter()
+            .map(|p| Self::conditional_select(p, &generator, p.is_identity()))
+            .collect();
+        let ptrs: [*const blst_p1; 2] = [&points[0].0, ptr::null()];
+        unsafe {
+            blst_p1s_to_affine(
+                q.as_mut_ptr() as *mut blst_p1_affine,
+                ptrs.as_ptr(),
+                p.len(),
+            )
+        };
+
+        let identity = G1Affine::identity();
+        for (q, p) in q.iter_mut().zip(p.iter()) {
+            q.conditional_assign(&identity, p.is_identity());
+        }
+    }
 }
 
 impl PrimeCurve for G1Projective {
@@ -1425,5 +1829,523 @@ mod tests {
         let pippenger = G1Projective::multi_exp(points.as_slice(), scalars.as_slice());
 
         assert_eq!(naive, pippenger);
+
+        assert_eq!(
+            G1Projective::multi_exp(&[], &scalars),
+            G1Projective::identity()
+        );
+        assert_eq!(
+            G1Projective::multi_exp(&points[..1], &scalars[..1]),
+            points[0] * scalars[0]
+        );
+        assert_eq!(
+            G1Projective::multi_exp(&points, &scalars[..5]),
+            G1Projective::multi_exp(&points[..5], &scalars[..5])
+        );
+    }
+
+    #[test]
+    fn test_affine_multi_exp() {
+        let mut rng = XorShiftRng::from_seed([
+            0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06,
+            0xbc, 0xe5,
+        ]);
+
+        for size in [0, 1, 2, 10, 100] {
+            let points: Vec<G1Projective> = (0..size)
+                .map(|i| {
+                    if i % 7 == 3 {
+                        G1Projective::identity()
+                    } else {
+                        G1Projective::random(&mut rng)
+                    }
+                })
+                .collect();
+            let scalars: Vec<Scalar> = (0..size).map(|_| Scalar::random(&mut rng)).collect();
+            let affine: Vec<G1Affine> = points.iter().map(|p| p.to_affine()).collect();
+
+            let naive: G1Projective = points.iter().zip(scalars.iter()).map(|(p, s)| p * s).sum();
+            assert_eq!(
+                G1Affine::multi_exp(&affine, &scalars),
+                naive,
+                "size = {}",
+                size
+            );
+        }
+
+        let points: Vec<G1Affine> = (0..10)
+            .map(|_| G1Projective::random(&mut rng).to_affine())
+            .collect();
+        let scalars: Vec<Scalar> = (0..10).map(|_| Scalar::random(&mut rng)).collect();
+        assert_eq!(
+            G1Affine::multi_exp(&points, &scalars[..5]),
+            G1Affine::multi_exp(&points[..5], &scalars[..5])
+        );
+        assert_eq!(
+            G1Affine::multi_exp(&points[..5], &scalars),
+            G1Affine::multi_exp(&points[..5], &scalars[..5])
+        );
+    }
+
+    #[test]
+    fn test_multi_exp_checked() {
+        let mut rng = XorShiftRng::from_seed([
+            0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06,
+            0xbc, 0xe5,
+        ]);
+
+        let points: Vec<G1Projective> = (0..10).map(|_| G1Projective::random(&mut rng)).collect();
+        let affine: Vec<G1Affine> = points.iter().map(|p| p.to_affine()).collect();
+        let scalars: Vec<Scalar> = (0..10).map(|_| Scalar::random(&mut rng)).collect();
+
+        for n in [1, 2, 10] {
+            let expected = G1Projective::multi_exp(&points[..n], &scalars[..n]);
+            assert_eq!(
+                G1Projective::multi_exp_checked(&points[..n], &scalars[..n]),
+                Ok(expected)
+            );
+            assert_eq!(
+                G1Affine::multi_exp_checked(&affine[..n], &scalars[..n]),
+                Ok(expected)
+            );
+        }
+
+        assert_eq!(
+            G1Projective::multi_exp_checked(&[], &[]),
+            Err(MsmError::Empty)
+        );
+        assert_eq!(G1Affine::multi_exp_checked(&[], &[]), Err(MsmError::Empty));
+
+        for (p, s) in [
+            (0, 1),
+            (1, 0),
+            (0, 10),
+            (10, 0),
+            (5, 10),
+            (10, 5),
+            (9, 10),
+            (10, 9),
+        ] {
+            let err = Err(MsmError::LengthMismatch {
+                points: p,
+                scalars: s,
+            });
+            assert_eq!(
+                G1Projective::multi_exp_checked(&points[..p], &scalars[..s]),
+                err
+            );
+            assert_eq!(
+                G1Affine::multi_exp_checked(&affine[..p], &scalars[..s]),
+                err
+            );
+        }
+    }
+
+    #[test]
+    fn test_batch_multi_exp() {
+        let mut rng = XorShiftRng::from_seed([
+            0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06,
+            0xbc, 0xe5,
+        ]);
+
+        let bases: Vec<G1Projective> = (0..100)
+            .map(|i| {
+                if i % 7 == 3 {
+                    G1Projective::identity()
+                } else {
+                    G1Projective::random(&mut rng)
+                }
+            })
+            .collect();
+        let scalars: Vec<Vec<Scalar>> = [100, 0, 1, 2, 37, 100, 150, 10]
+            .iter()
+            .map(|&n| (0..n).map(|_| Scalar::random(&mut rng)).collect())
+            .collect();
+        let scalars: Vec<&[Scalar]> = scalars.iter().map(|s| s.as_slice()).collect();
+
+        let batch = G1Projective::batch_multi_exp(&bases, &scalars);
+        assert_eq!(batch.len(), scalars.len());
+        for (res, scalars) in batch.iter().zip(scalars.iter()) {
+            let naive: G1Projective = bases.iter().zip(scalars.iter()).map(|(p, s)| p * s).sum();
+            assert_eq!(*res, naive, "n = {}", scalars.len());
+            assert_eq!(
+                *res,
+                G1Projective::multi_exp(&bases, scalars),
+                "n = {}",
+                scalars.len()
+            );
+        }
+
+        assert!(G1Projective::batch_multi_exp(&bases, &[]).is_empty());
+        assert_eq!(
+            G1Projective::batch_multi_exp(&[], &scalars),
+            vec![G1Projective::identity(); scalars.len()]
+        );
+    }
+
+    #[test]
+    fn test_msm_bases() {
+        use crate::G1MsmBases;
+        use core::ops::Range;
+
+        const SIZE: usize = 40;
+        let mut rng = XorShiftRng::from_seed([
+            0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06,
+            0xbc, 0xe5,
+        ]);
+
+        let points: Vec<G1Projective> = (0..SIZE)
+            .map(|i| {
+                if i % 7 == 3 {
+                    G1Projective::identity()
+                } else {
+                    G1Projective::random(&mut rng)
+                }
+            })
+            .collect();
+        let scalars: Vec<Scalar> = (0..SIZE).map(|_| Scalar::random(&mut rng)).collect();
+        let naive = |range: Range<usize>, scalars: &[Scalar]| -> G1Projective {
+            points[range]
+                .iter()
+                .zip(scalars.iter())
+                .map(|(p, s)| p * s)
+                .sum()
+        };
+
+        let plain = G1MsmBases::from_projective(&points);
+        let mut precomputed = G1MsmBases::new(plain.points());
+        precomputed.precompute(4);
+        assert_eq!(plain.len(), SIZE);
+        assert_eq!(plain.wbits(), None);
+        assert_eq!(precomputed.wbits(), Some(4));
+
+        for bases in [&plain, &precomputed] {
+            assert_eq!(bases.msm(&scalars), naive(0..SIZE, &scalars));
+            assert_eq!(bases.msm(&scalars[..5]), naive(0..5, &scalars));
+            assert_eq!(bases.msm(&[]), G1Projective::identity());
+
+            for range in [0..0, 0..1, 3..4, 2..10, 10..SIZE, 0..SIZE] {
+                assert_eq!(
+                    bases.msm_range(range.clone(), &scalars),
+                    naive(range.clone(), &scalars),
+                    "range = {:?}",
+                    range
+                );
+            }
+            assert_eq!(bases.msm_range(5..20, &scalars[..3]), naive(5..8, &scalars));
+        }
+
+        let mut empty = G1MsmBases::new(&[]);
+        assert!(empty.is_empty());
+        assert_eq!(empty.msm(&scalars), G1Projective::identity());
+        empty.precompute(2);
+        assert_eq!(empty.msm(&scalars), G1Projective::identity());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_msm_bases_range_out_of_bounds() {
+        let bases = crate::G1MsmBases::new(&[G1Affine::generator(); 3]);
+        bases.msm_range(2..4, &[Scalar::one(); 2]);
+    }
+
+    #[test]
+    fn test_multi_exp_small_scalars() {
+        let mut rng = XorShiftRng::from_seed([
+            0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06,
+            0xbc, 0xe5,
+        ]);
+
+        let points: Vec<G1Projective> = (0..40)
+            .map(|i| {
+                if i % 7 == 3 {
+                    G1Projective::identity()
+                } else {
+                    G1Projective::random(&mut rng)
+                }
+            })
+            .collect();
+        let words: Vec<u64> = (0..40).map(|_| rng.next_u64()).collect();
+        let bits: Vec<bool> = words.iter().map(|w| w & 1 == 1).collect();
+        let scalars: Vec<Scalar> = (0..40).map(|_| Scalar::random(&mut rng)).collect();
+
+        for n in [0, 1, 2, 40] {
+            let naive: G1Projective = points[..n]
+                .iter()
+                .zip(words.iter())
+                .map(|(p, w)| p * Scalar::from(*w))
+                .sum();
+            assert_eq!(G1Projective::multi_exp_u64(&points[..n], &words), naive);
+
+            let naive: G1Projective = points[..n]
+                .iter()
+                .zip(bits.iter())
+                .filter(|(_, b)| **b)
+                .map(|(p, _)| p)
+                .sum();
+            assert_eq!(G1Projective::multi_exp_bits(&points[..n], &bits), naive);
+
+            for nbits in [1, 7, 8, 9, 64, 200, 255] {
+                // The scalars reduced to their lowest `nbits` bits.
+                let truncated: Vec<Scalar> = scalars
+                    .iter()
+                    .map(|s| {
+                        let mut bytes = s.to_bytes_le();
+                        for (i, byte) in bytes.iter_mut().enumerate() {
+                            for j in 0..8 {
+                                if 8 * i + j >= nbits {
+                                    *byte &= !(1 << j);
+                                }
+                            }
+                        }
+                        Scalar::from_bytes_le(&bytes).unwrap()
+                    })
+                    .collect();
+                assert_eq!(
+                    G1Projective::multi_exp_nbits(&points[..n], &scalars, nbits),
+                    G1Projective::multi_exp(&points[..n], &truncated),
+                    "n = {}, nbits = {}",
+                    n,
+                    nbits
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_endomorphism() {
+        let mut rng = XorShiftRng::from_seed([
+            0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06,
+            0xbc, 0xe5,
+        ]);
+
+        // The endomorphism is the multiplication by `-x^2`.
+        let x_abs = Scalar::from(X_ABS);
+        let lambda = -x_abs.square();
+        for _ in 0..10 {
+            let p = G1Projective::random(&mut rng);
+            assert_eq!(p.endomorphism(), p * lambda);
+            assert_eq!(G1Projective::from(p.to_affine().endomorphism()), p * lambda);
+            assert_eq!(p.endomorphism().endomorphism().endomorphism(), p);
+        }
+        assert!(bool::from(
+            G1Affine::identity().endomorphism().is_identity()
+        ));
+        assert!(bool::from(
+            G1Projective::identity().endomorphism().is_identity()
+        ));
+    }
+
+    #[test]
+    fn test_mul_by_generator() {
+        let mut rng = XorShiftRng::from_seed([
+            0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06,
+            0xbc, 0xe5,
+        ]);
+
+        let scalars = (0..10).map(|_| Scalar::random(&mut rng)).chain([
+            Scalar::zero(),
+            Scalar::one(),
+            -Scalar::one(),
+        ]);
+        for s in scalars {
+            assert_eq!(
+                G1Projective::mul_by_generator(&s),
+                G1Projective::generator() * s
+            );
+        }
+    }
+
+    #[test]
+    fn test_batch_normalize() {
+        let mut rng = XorShiftRng::from_seed([
+            0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06,
+            0xbc, 0xe5,
+        ]);
+
+        for size in [0, 1, 2, 10, 100] {
+            let points: Vec<G1Projective> = (0..size)
+                .map(|i| {
+                    if i % 7 == 3 {
+                        G1Projective::identity()
+                    } else {
+                        G1Projective::random(&mut rng)
+                    }
+                })
+                .collect();
+            let mut affine = vec![G1Affine::generator(); size];
+            G1Projective::batch_normalize(&points, &mut affine);
+            for (p, q) in points.iter().zip(affine.iter()) {
+                assert_eq!(p.to_affine(), *q);
+            }
+        }
+
+        let mut affine = [G1Affine::generator()];
+        G1Projective::batch_normalize(&[G1Projective::identity()], &mut affine);
+        assert!(bool::from(affine[0].is_identity()));
+    }
+
+    #[cfg(feature = "parallel")]
+    #[test]
+    fn test_batch_from_compressed() {
+        let mut rng = XorShiftRng::from_seed([
+            0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06,
+            0xbc, 0xe5,
+        ]);
+
+        // A point on the curve but outside the subgroup.
+        let off_subgroup = loop {
+            let mut bytes = [0u8; COMPRESSED_SIZE];
+            rng.fill_bytes(&mut bytes);
+            bytes[0] = (bytes[0] & 0x1f) | 0x80;
+            let p = G1Affine::from_compressed_unchecked(&bytes);
+            if bool::from(p.is_some()) {
+                assert_eq!(p.unwrap().is_torsion_free().unwrap_u8(), 0);
+                break bytes;
+            }
+        };
+
+        let mut not_on_curve = loop {
+            let mut bytes = [0u8; COMPRESSED_SIZE];
+            rng.fill_bytes(&mut bytes);
+            bytes[0] = (bytes[0] & 0x1f) | 0x80;
+            if bool::from(G1Affine::from_compressed_unchecked(&bytes).is_none()) {
+                break bytes;
+            }
+        };
+        not_on_curve[0] |= 0x20;
+
+        let mut bytes: Vec<[u8; COMPRESSED_SIZE]> = (0..20)
+            .map(|_| G1Projective::random(&mut rng).to_affine().to_compressed())
+            .collect();
+        bytes[3] = G1Affine::identity().to_compressed();
+        bytes[5] = off_subgroup;
+        bytes[11] = not_on_curve;
+        bytes[17][0] &= 0x7f;
+
+        let points = G1Affine::batch_from_compressed(&bytes);
+        assert_eq!(points.len(), bytes.len());
+        for (p, b) in points.iter().zip(bytes.iter()) {
+            let expected = G1Affine::from_compressed(b);
+            assert_eq!(p.is_some().unwrap_u8(), expected.is_some().unwrap_u8());
+            if bool::from(expected.is_some()) {
+                assert_eq!(p.unwrap(), expected.unwrap());
+            }
+        }
+        let valid = points.iter().filter(|p| bool::from(p.is_some())).count();
+        assert_eq!(valid, bytes.len() - 3);
+
+        assert!(G1Affine::batch_from_compressed(&[]).is_empty());
+    }
+
+    // The RFC 9380 vectors are checked in `tests/hash_to_curve.rs`.
+    #[test]
+    fn test_encode_to_curve() {
+        const DST: &[u8] = b"QUUX-V01-CS02-with-BLS12381G1_XMD:SHA-256_SSWU_NU_";
+
+        let p = G1Projective::encode_to_curve(b"abc", DST, &[]);
+        assert!(bool::from(p.is_on_curve()));
+        assert!(bool::from(p.to_affine().is_torsion_free()));
+        assert_ne!(p, G1Projective::hash_to_curve(b"abc", DST, &[]));
+
+        // `aug` is prepended to the message.
+        assert_eq!(G1Projective::encode_to_curve(b"c", DST, b"ab"), p);
+    }
+
+    #[test]
+    fn test_map_to_curve() {
+        let mut rng = XorShiftRng::from_seed([
+            0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06,
+            0xbc, 0xe5,
+        ]);
+
+        for _ in 0..10 {
+            let u = Fp::random(&mut rng);
+            let p = G1Projective::map_to_curve(&u);
+            assert!(bool::from(p.is_on_curve()));
+            // The sign of `u` only picks the sign of `y`.
+            assert_eq!(G1Projective::map_to_curve(&-u), -p);
+        }
+
+        // The exceptional case of the simplified SWU map.
+        assert!(bool::from(
+            G1Projective::map_to_curve(&Fp::zero()).is_on_curve()
+        ));
+    }
+
+    #[test]
+    fn test_hash_to_curve_with() {
+        use crate::hash_to_field::{ExpandMsgXmdSha256, ExpandMsgXofShake256};
+
+        const DST: &[u8] = b"QUUX-V01-CS02-with-BLS12381G1_XMD:SHA-256_SSWU_RO_";
+
+        for (msg, aug) in [("", ""), ("abc", ""), ("abc", "aug"), ("", "aug")].iter() {
+            assert_eq!(
+                G1Projective::hash_to_curve_with::<ExpandMsgXmdSha256>(
+                    msg.as_bytes(),
+                    DST,
+                    aug.as_bytes()
+                ),
+                G1Projective::hash_to_curve(msg.as_bytes(), DST, aug.as_bytes())
+            );
+        }
+
+        // RFC 9380 has no `XOF:SHAKE-256` vectors for BLS12-381. These match
+        // `G1Projective::hash::<ExpandMsgXof<Shake256>>` of `bls12_381_plus` 0.8.18.
+        const XOF_DST: &[u8] = b"QUUX-V01-CS02-with-BLS12381G1_XOF:SHAKE-256_SSWU_RO_";
+        let expected = [
+            (
+                "",
+                [
+                    "00b3fab2ec4ab0f67554f7d91b4ba1983a00c9538930ba1cac3ae8aa6684bed70f79c5da40173923196c820c03938c4a",
+                    "004622d7a4871c2fe37b73472dacc3ea2d073d41527b73df241553f6f727432109f7681aa871949ca64eec914f871e2f",
+                ],
+            ),
+            (
+                "abc",
+                [
+                    "14459b5cd1433d091bf8e6b5d8538a2046678f8aaf77144c453cd49be97104885b1e3090d979dbf42450a7f7a78a727b",
+                    "07a27b512e2ce37962d0a70aa1206bd763e466cea1a604c860e69fd86590f21d720d1fea0e69f0aed82606d693223833",
+                ],
+            ),
+        ];
+
+        for (msg, expected) in expected.iter() {
+            let p = G1Projective::hash_to_curve_with::<ExpandMsgXofShake256>(
+                msg.as_bytes(),
+                XOF_DST,
+                &[],
+            );
+            assert_eq!(
+                hex::encode(p.to_affine().to_uncompressed()),
+                expected.concat()
+            );
+            assert!(bool::from(p.to_affine().is_torsion_free()));
+        }
+    }
+
+    #[test]
+    fn test_clear_cofactor() {
+        let mut rng = XorShiftRng::from_seed([
+            0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06,
+            0xbc, 0xe5,
+        ]);
+
+        for _ in 0..10 {
+            let p = G1Projective::map_to_curve(&Fp::random(&mut rng));
+            assert!(bool::from(p.is_on_curve()));
+            assert!(!bool::from(p.to_affine().is_torsion_free()));
+            assert!(bool::from(p.clear_cofactor().to_affine().is_torsion_free()));
+
+            // On G1, `h_eff = 1 - x`.
+            let p = G1Projective::random(&mut rng);
+            assert_eq!(
+                p.clear_cofactor(),
+                p * Scalar::from(0xd201_0000_0001_0001u64)
+            );
+        }
+        assert!(bool::from(
+            G1Projective::identity().clear_cofactor().is_identity()
+        ));
     }
 }
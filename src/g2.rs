@@ -5,6 +5,7 @@ use core::{
     fmt,
     iter::Sum,
     ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+    ptr,
 };
 
 use blst::*;
@@ -12,10 +13,24 @@ use group::{
     prime::{PrimeCurve, PrimeCurveAffine, PrimeGroup},
     Curve, Group, GroupEncoding, UncompressedEncoding, WnafGroup,
 };
+use once_cell::sync::OnceCell;
 use rand_core::RngCore;
 use subtle::{Choice, ConditionallySelectable, CtOption};
 
-use crate::{fp2::Fp2, Bls12, Engine, G1Affine, Gt, PairingCurveAffine, Scalar};
+use crate::{
+    fixed_base::FixedBaseTable,
+    fp::{FROBENIUS_COEFF_FP6_C1, FROBENIUS_COEFF_FP6_C2},
+    fp12::FROBENIUS_COEFF_FP12_C1,
+    fp2::Fp2,
+    hash_to_field::ExpandMsg,
+    map_to_curve::{iso_map_g2, map_to_curve_simple_swu_g2},
+    msm::{check_lengths, MsmError},
+    scalar::X_ABS,
+    Bls12, Engine, G1Affine, Gt, PairingCurveAffine, Scalar,
+};
+
+#[cfg(feature = "parallel")]
+use crate::msm::par_multi_exp;
 
 /// This is an element of $\mathbb{G}_2$ represented in the affine coordinate space.
 /// It is ideal to keep elements in this representation to reduce memory usage and
@@ -24,8 +39,8 @@ use crate::{fp2::Fp2, Bls12, Engine, G1Affine, Gt, PairingCurveAffine, Scalar};
 #[repr(transparent)]
 pub struct G2Affine(pub(crate) blst_p2_affine);
 
-const COMPRESSED_SIZE: usize = 96;
-const UNCOMPRESSED_SIZE: usize = 192;
+pub(crate) const COMPRESSED_SIZE: usize = 96;
+pub(crate) const UNCOMPRESSED_SIZE: usize = 192;
 
 impl fmt::Debug for G2Affine {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
@@ -189,6 +204,24 @@ impl Sub<&G2Affine> for &G2Projective {
     }
 }
 
+impl Add<&G2Affine> for &G2Affine {
+    type Output = G2Projective;
+
+    #[inline]
+    fn add(self, rhs: &G2Affine) -> G2Projective {
+        G2Projective::from(self).add_mixed(rhs)
+    }
+}
+
+impl Sub<&G2Affine> for &G2Affine {
+    type Output = G2Projective;
+
+    #[inline]
+    fn sub(self, rhs: &G2Affine) -> G2Projective {
+        self + -rhs
+    }
+}
+
 impl AddAssign<&G2Projective> for G2Projective {
     #[inline]
     fn add_assign(&mut self, rhs: &G2Projective) {
@@ -250,6 +283,7 @@ impl MulAssign<&Scalar> for G2Affine {
 impl_add_sub!(G2Projective);
 impl_add_sub!(G2Projective, G2Affine);
 impl_add_sub!(G2Affine, G2Projective, G2Projective);
+impl_add_sub!(G2Affine, G2Affine, G2Projective);
 
 impl_add_sub_assign!(G2Projective);
 impl_add_sub_assign!(G2Projective, G2Affine);
@@ -272,6 +306,24 @@ where
     }
 }
 
+impl Sum<G2Affine> for G2Projective {
+    fn sum<I>(iter: I) -> Self
+    where
+        I: Iterator<Item = G2Affine>,
+    {
+        iter.fold(Self::identity(), |acc, item| acc + item)
+    }
+}
+
+impl<'a> Sum<&'a G2Affine> for G2Projective {
+    fn sum<I>(iter: I) -> Self
+    where
+        I: Iterator<Item = &'a G2Affine>,
+    {
+        iter.fold(Self::identity(), |acc, item| acc + item)
+    }
+}
+
 impl PrimeCurveAffine for G2Affine {
     type Scalar = Scalar;
     type Curve = G2Projective;
@@ -312,6 +364,46 @@ impl ConditionallySelectable for G2Projective {
     }
 }
 
+/// Multi-exponentiation of `points` by the `nbits`-bit scalars in `scalar_bytes`, which holds
+/// one little-endian scalar of `(nbits + 7) / 8` bytes per point, using `blst`'s implementation
+/// of Pippenger's algorithm on the calling thread.
+pub(crate) fn p2s_mult_pippenger(
+    points: &[G2Affine],
+    scalar_bytes: &[u8],
+    nbits: usize,
+) -> G2Projective {
+    let n = points.len();
+    assert!(scalar_bytes.len() >= n * ((nbits + 7) / 8));
+
+    let mut out = blst_p2::default();
+    match n {
+        0 => {}
+        // blst's Pippenger implementation gives wrong results for a single point.
+        1 => unsafe {
+            let mut point = blst_p2::default();
+            blst_p2_from_affine(&mut point, &points[0].0);
+            blst_p2_mult(&mut out, &point, scalar_bytes.as_ptr(), nbits);
+        },
+        _ => {
+            let mut scratch = vec![0u64; unsafe { blst_p2s_mult_pippenger_scratch_sizeof(n) } / 8];
+            let p: [*const blst_p2_affine; 2] = [&points[0].0, ptr::null()];
+            let s: [*const u8; 2] = [scalar_bytes.as_ptr(), ptr::null()];
+            unsafe {
+                blst_p2s_mult_pippenger(
+                    &mut out,
+                    p.as_ptr(),
+                    n,
+                    s.as_ptr(),
+                    nbits,
+                    scratch.as_mut_ptr(),
+                )
+            };
+        }
+    }
+
+    G2Projective(out)
+}
+
 impl G2Affine {
     /// Serializes this element into compressed form.
     pub fn to_compressed(&self) -> [u8; COMPRESSED_SIZE] {
@@ -371,6 +463,19 @@ impl G2Affine {
         CtOption::new(G2Affine(raw), Choice::from(success as u8))
     }
 
+    /// Attempts to deserialize a batch of compressed elements, returning one result per element.
+    /// Each result matches the one from `from_compressed()`, and the square roots and subgroup
+    /// checks are spread across the threads of the current `rayon` pool.
+    ///
+    /// The subgroup checks are not batched into a random linear combination, which would be
+    /// unsound because the cofactor has small prime factors.
+    #[cfg(feature = "parallel")]
+    pub fn batch_from_compressed(bytes: &[[u8; COMPRESSED_SIZE]]) -> Vec<CtOption<Self>> {
+        use rayon::prelude::*;
+
+        bytes.par_iter().map(G2Affine::from_compressed).collect()
+    }
+
     /// Returns true if this point is free of an $h$-torsion component, and so it
     /// exists within the $q$-order subgroup $\mathbb{G}_2$. This should always return true
     /// unless an "unchecked" API was used.
@@ -409,6 +514,41 @@ impl G2Affine {
     pub const fn compressed_size() -> usize {
         COMPRESSED_SIZE
     }
+
+    /// Perform a multi-exponentiation, aka "multi-scalar-multiplication" (MSM) using `blst`'s implementation of Pippenger's algorithm.
+    /// Unlike [`G2Projective::multi_exp`], the points are passed to `blst` without normalizing
+    /// them first, and the computation runs on the calling thread. Extra points or scalars are
+    /// ignored.
+    pub fn multi_exp(points: &[Self], scalars: &[Scalar]) -> G2Projective {
+        let n = points.len().min(scalars.len());
+
+        let mut scalar_bytes: Vec<u8> = Vec::with_capacity(n * 32);
+        for a in scalars[..n].iter().map(|s| s.to_bytes_le()) {
+            scalar_bytes.extend_from_slice(&a);
+        }
+
+        p2s_mult_pippenger(&points[..n], &scalar_bytes, 255)
+    }
+
+    /// Like [`G2Affine::multi_exp`], but fails if `points` and `scalars` have different lengths,
+    /// or are both empty.
+    pub fn multi_exp_checked(
+        points: &[Self],
+        scalars: &[Scalar],
+    ) -> Result<G2Projective, MsmError> {
+        check_lengths(points.len(), scalars.len())?;
+        Ok(Self::multi_exp(points, scalars))
+    }
+
+    /// Like [`G2Affine::multi_exp`], but splits the points across the threads of the current
+    /// `rayon` pool and sums the partial results. Extra points or scalars are ignored.
+    ///
+    /// The number of threads can be set with `RAYON_NUM_THREADS`, or by calling this inside
+    /// `rayon::ThreadPool::install`.
+    #[cfg(feature = "parallel")]
+    pub fn multi_exp_parallel(points: &[Self], scalars: &[Scalar]) -> G2Projective {
+        par_multi_exp(points, scalars, Self::multi_exp)
+    }
 }
 
 /// This is an element of $\mathbb{G}_2$ represented in the projective coordinate space.
@@ -432,6 +572,12 @@ impl fmt::Display for G2Projective {
     }
 }
 
+impl Default for G2Projective {
+    fn default() -> G2Projective {
+        G2Projective::identity()
+    }
+}
+
 impl AsRef<blst_p2> for G2Projective {
     fn as_ref(&self) -> &blst_p2 {
         &self.0
@@ -591,6 +737,74 @@ impl G2Projective {
         res
     }
 
+    /// Like [`G2Projective::hash_to_curve`], but with the `expand_message` function `X`, such as
+    /// [`ExpandMsgXofShake256`](crate::ExpandMsgXofShake256) for the
+    /// `BLS12381G2_XOF:SHAKE-256_SSWU_RO_` suite.
+    pub fn hash_to_curve_with<X: ExpandMsg>(msg: &[u8], dst: &[u8], aug: &[u8]) -> Self {
+        let u = Fp2::hash_to_field::<X>(&[aug, msg].concat(), dst, 2);
+        (Self::map_to_curve(&u[0]) + Self::map_to_curve(&u[1])).clear_cofactor()
+    }
+
+    /// Encode to curve algorithm, the nonuniform encoding of RFC 9380.
+    ///
+    /// This maps a single field element to the curve, so it costs about half as much as
+    /// `hash_to_curve`, but its output is not uniformly distributed. Only use it for protocols
+    /// specified with an `_NU_` suite.
+    pub fn encode_to_curve(msg: &[u8], dst: &[u8], aug: &[u8]) -> Self {
+        let mut res = Self::identity();
+        unsafe {
+            blst_encode_to_g2(
+                &mut res.0,
+                msg.as_ptr(),
+                msg.len(),
+                dst.as_ptr(),
+                dst.len(),
+                aug.as_ptr(),
+                aug.len(),
+            );
+        }
+        res
+    }
+
+    /// The `map_to_curve` function of RFC 9380, the simplified SWU map followed by the
+    /// 3-isogeny. The result is on the curve, but not in $\mathbb{G}_2$ until it is multiplied by
+    /// the cofactor with [`G2Projective::clear_cofactor`].
+    pub fn map_to_curve(u: &Fp2) -> Self {
+        let (x, y) = Self::map_to_curve_simple_swu(u);
+        Self::iso_map(&x, &y)
+    }
+
+    /// The simplified SWU map of RFC 9380 to the curve `E': y^2 = x^3 + A' x + B'`, which is
+    /// 3-isogenous to the G2 curve. Returns the affine coordinates of a point of `E'`.
+    pub fn map_to_curve_simple_swu(u: &Fp2) -> (Fp2, Fp2) {
+        map_to_curve_simple_swu_g2(u)
+    }
+
+    /// The 3-isogeny of RFC 9380 from `E'` to the G2 curve, applied to the affine point `(x, y)`
+    /// of `E'`, such as an output of [`G2Projective::map_to_curve_simple_swu`].
+    pub fn iso_map(x: &Fp2, y: &Fp2) -> Self {
+        let [x, y, z] = iso_map_g2(x, y);
+        G2Projective::from_raw_unchecked(x, y, z)
+    }
+
+    /// Multiplies `self` by the effective cofactor `h_eff` of RFC 9380, which maps any point of
+    /// the curve to $\mathbb{G}_2$. Computed with the endomorphism [`G2Projective::psi`] as
+    /// `[x^2 - x - 1] P + [x - 1] ψ(P) + ψ^2(2P)`.
+    pub fn clear_cofactor(&self) -> Self {
+        let t1 = self.mul_by_x();
+        let t2 = self.psi();
+        let t3 = self.double().psi2() - t2;
+        let t2 = (t1 + t2).mul_by_x();
+        t3 + t2 - t1 - self
+    }
+
+    /// Multiplies `self` by the BLS parameter `x`.
+    fn mul_by_x(&self) -> Self {
+        let mut out = blst_p2::default();
+        unsafe { blst_p2_mult(&mut out, &self.0, X_ABS.to_le_bytes().as_ptr(), 64) };
+        -G2Projective(out)
+    }
+
     /// Perform a multi-exponentiation, aka "multi-scalar-multiplication" (MSM) using `blst`'s implementation of Pippenger's algorithm.
     /// Note: `scalars` is cloned in this method.
     pub fn multi_exp(points: &[Self], scalars: &[Scalar]) -> Self {
@@ -600,18 +814,209 @@ impl G2Projective {
             scalars.len()
         };
 
-        let points =
-            unsafe { std::slice::from_raw_parts(points.as_ptr() as *const blst_p2, points.len()) };
-        let points = p2_affines::from(points);
-
         let mut scalar_bytes: Vec<u8> = Vec::with_capacity(n * 32);
-        for a in scalars.iter().map(|s| s.to_bytes_le()) {
+        for a in scalars[..n].iter().map(|s| s.to_bytes_le()) {
             scalar_bytes.extend_from_slice(&a);
         }
 
-        let res = points.mult(scalar_bytes.as_slice(), 255);
+        Self::multi_exp_bytes(&points[..n], &scalar_bytes, 255)
+    }
+
+    /// Like [`G2Projective::multi_exp`], but only uses the lowest `nbits` bits of each scalar,
+    /// so that `blst` skips the windows of the higher bits. Extra points or scalars are ignored.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `nbits` is not between 1 and 255.
+    pub fn multi_exp_nbits(points: &[Self], scalars: &[Scalar], nbits: usize) -> Self {
+        assert!(
+            (1..=255).contains(&nbits),
+            "nbits must be between 1 and 255"
+        );
+        let n = points.len().min(scalars.len());
+        let nbytes = (nbits + 7) / 8;
+        let mask = 0xff >> (8 * nbytes - nbits);
+
+        let mut scalar_bytes: Vec<u8> = Vec::with_capacity(n * nbytes);
+        for a in scalars[..n].iter().map(|s| s.to_bytes_le()) {
+            scalar_bytes.extend_from_slice(&a[..nbytes]);
+            *scalar_bytes.last_mut().unwrap() &= mask;
+        }
+
+        Self::multi_exp_bytes(&points[..n], &scalar_bytes, nbits)
+    }
+
+    /// Like [`G2Projective::multi_exp`], but for `u64` scalars, using 64-bit windows.
+    pub fn multi_exp_u64(points: &[Self], scalars: &[u64]) -> Self {
+        let n = points.len().min(scalars.len());
+
+        let mut scalar_bytes: Vec<u8> = Vec::with_capacity(n * 8);
+        for a in scalars[..n].iter().map(|s| s.to_le_bytes()) {
+            scalar_bytes.extend_from_slice(&a);
+        }
+
+        Self::multi_exp_bytes(&points[..n], &scalar_bytes, 64)
+    }
+
+    /// Like [`G2Projective::multi_exp`], but for scalars that are 0 or 1.
+    pub fn multi_exp_bits(points: &[Self], bits: &[bool]) -> Self {
+        let n = points.len().min(bits.len());
+        let scalar_bytes: Vec<u8> = bits[..n].iter().map(|&b| u8::from(b)).collect();
+
+        Self::multi_exp_bytes(&points[..n], &scalar_bytes, 1)
+    }
+
+    /// Multi-exponentiation of `points` by the `nbits`-bit scalars in `scalar_bytes`, which holds
+    /// one little-endian scalar of `(nbits + 7) / 8` bytes per point.
+    fn multi_exp_bytes(points: &[Self], scalar_bytes: &[u8], nbits: usize) -> Self {
+        // `blst`'s batch conversion to affine coordinates breaks on the point at infinity, which
+        // adds nothing to the sum anyway.
+        let nbytes = (nbits + 7) / 8;
+        let mut bases = Vec::with_capacity(points.len());
+        let mut bytes = Vec::with_capacity(scalar_bytes.len());
+        for (point, scalar) in points.iter().zip(scalar_bytes.chunks(nbytes)) {
+            if !bool::from(point.is_identity()) {
+                bases.push(point.0);
+                bytes.extend_from_slice(scalar);
+            }
+        }
+
+        match bases.len() {
+            0 => Self::identity(),
+            // blst's Pippenger implementation gives wrong results for a single point.
+            1 => {
+                let mut out = blst_p2::default();
+                unsafe { blst_p2_mult(&mut out, &bases[0], bytes.as_ptr(), nbits) };
+                G2Projective(out)
+            }
+            _ => G2Projective(p2_affines::from(&bases).mult(&bytes, nbits)),
+        }
+    }
+
+    /// Like [`G2Projective::multi_exp`], but fails if `points` and `scalars` have different
+    /// lengths, or are both empty.
+    pub fn multi_exp_checked(points: &[Self], scalars: &[Scalar]) -> Result<Self, MsmError> {
+        check_lengths(points.len(), scalars.len())?;
+        Ok(Self::multi_exp(points, scalars))
+    }
+
+    /// Like [`G2Projective::multi_exp`], but splits the points across the threads of the current
+    /// `rayon` pool and sums the partial results. Each thread normalizes its chunk and passes it to
+    /// [`G2Affine::multi_exp`], so `blst` starts no threads of its own. Extra points or scalars
+    /// are ignored.
+    ///
+    /// The number of threads can be set with `RAYON_NUM_THREADS`, or by calling this inside
+    /// `rayon::ThreadPool::install`.
+    #[cfg(feature = "parallel")]
+    pub fn multi_exp_parallel(points: &[Self], scalars: &[Scalar]) -> Self {
+        par_multi_exp(points, scalars, |points, scalars| {
+            let mut affine = vec![G2Affine::identity(); points.len()];
+            Self::batch_normalize(points, &mut affine);
+            G2Affine::multi_exp(&affine, scalars)
+        })
+    }
+
+    /// Returns the image of this point by the untwist-Frobenius-twist endomorphism
+    /// `ψ(x, y) = (x^p / (u + 1)^((p - 1) / 3), y^p / (u + 1)^((p - 1) / 2))`. On
+    /// $\mathbb{G}_2$ it is the multiplication by the BLS parameter `x`.
+    pub fn psi(&self) -> Self {
+        // With `γ = (u + 1)^((p - 1) / 3)`, the norm `γ^(p + 1)` is the cube root of unity
+        // `ω = (u + 1)^((p^2 - 1) / 3)` of `Fp`, so `x^p / γ = (x γ)^p ω^2`. And
+        // `β = (u + 1)^((p - 1) / 2)` has norm `-1`, as `u + 1` is not a square, so
+        // `y^p / β = -(y β)^p`.
+        //
+        // The Frobenius map commutes with the Jacobian coordinates, which are scaled by powers of
+        // `z`.
+        let gamma = FROBENIUS_COEFF_FP6_C1[1];
+        let beta = Fp2(FROBENIUS_COEFF_FP12_C1[1]) * gamma;
+
+        let mut x = self.x() * gamma;
+        x.frobenius_map(1);
+        let mut y = self.y() * beta;
+        y.frobenius_map(1);
+        let mut z = self.z();
+        z.frobenius_map(1);
+
+        G2Projective(blst_p2 {
+            x: (x * FROBENIUS_COEFF_FP6_C2[2]).0,
+            y: (-y).0,
+            z: z.0,
+        })
+    }
+
+    /// Returns `ψ(ψ(self))`, computed directly as `(x / 2^((p - 1) / 3), -y)`. On
+    /// $\mathbb{G}_2$ it is the multiplication by `x^2`.
+    pub fn psi2(&self) -> Self {
+        // `2^((p - 1) / 3)` is the cube root of unity `ω` of `Fp`, and `ω^2 = 1 / ω`.
+        let omega2 = FROBENIUS_COEFF_FP6_C2[2].c0();
+        let x = self.x();
+
+        G2Projective(blst_p2 {
+            x: Fp2::new(x.c0() * omega2, x.c1() * omega2).0,
+            y: (-self.y()).0,
+            z: self.0.z,
+        })
+    }
 
-        G2Projective(res)
+    /// Multiplies the generator by `scalar` in constant time, with a [`FixedBaseTable`] of the
+    /// generator that is built on first use.
+    pub fn mul_by_generator(scalar: &Scalar) -> Self {
+        static TABLE: OnceCell<FixedBaseTable<G2Projective>> = OnceCell::new();
+        TABLE
+            .get_or_init(|| FixedBaseTable::new(&Self::generator()))
+            .mul(scalar)
+    }
+
+    /// Performs one multi-exponentiation per entry of `scalars`, all against `bases`, using
+    /// `blst`'s implementation of Pippenger's algorithm. The bases are normalized once, and the
+    /// bucket and scratch memory is shared across the batch. Extra bases or scalars in each entry
+    /// are ignored, so the `i`-th result is `G2Projective::multi_exp(bases, scalars[i])`.
+    ///
+    /// Unlike [`G2Projective::multi_exp`], each multi-exponentiation runs on the calling thread.
+    pub fn batch_multi_exp(bases: &[Self], scalars: &[&[Scalar]]) -> Vec<Self> {
+        let max_n = scalars
+            .iter()
+            .map(|s| s.len().min(bases.len()))
+            .max()
+            .unwrap_or(0);
+        let mut affine = vec![G2Affine::identity(); max_n];
+        Self::batch_normalize(&bases[..max_n], &mut affine);
+
+        let mut scratch = vec![0u64; unsafe { blst_p2s_mult_pippenger_scratch_sizeof(max_n) } / 8];
+        let mut scalar_bytes: Vec<u8> = Vec::with_capacity(max_n * 32);
+        scalars
+            .iter()
+            .map(|scalars| {
+                let n = bases.len().min(scalars.len());
+                match n {
+                    0 => return Self::identity(),
+                    // blst's Pippenger implementation gives wrong results for a single point.
+                    1 => return bases[0] * scalars[0],
+                    _ => {}
+                }
+
+                scalar_bytes.clear();
+                for a in scalars[..n].iter().map(|s| s.to_bytes_le()) {
+                    scalar_bytes.extend_from_slice(&a);
+                }
+
+                let p: [*const blst_p2_affine; 2] = [&affine[0].0, ptr::null()];
+                let s: [*const u8; 2] = [scalar_bytes.as_ptr(), ptr::null()];
+                let mut out = blst_p2::default();
+                unsafe {
+                    blst_p2s_mult_pippenger(
+                        &mut out,
+                        p.as_ptr(),
+                        n,
+                        s.as_ptr(),
+                        255,
+                        scratch.as_mut_ptr(),
+                    )
+                };
+
+                G2Projective(out)
+            })
+            .collect()
     }
 }
 
@@ -619,25 +1024,12 @@ impl Group for G2Projective {
     type Scalar = Scalar;
 
     fn random(mut rng: impl RngCore) -> Self {
-        let mut out = blst_p2::default();
         let mut msg = [0u8; 64];
         rng.fill_bytes(&mut msg);
         const DST: [u8; 16] = [0; 16];
         const AUG: [u8; 16] = [0; 16];
 
-        unsafe {
-            blst_encode_to_g2(
-                &mut out,
-                msg.as_ptr(),
-                msg.len(),
-                DST.as_ptr(),
-                DST.len(),
-                AUG.as_ptr(),
-                AUG.len(),
-            )
-        };
-
-        G2Projective(out)
+        Self::encode_to_curve(&msg, &DST, &AUG)
     }
 
     fn identity() -> Self {
@@ -684,6 +1076,36 @@ impl Curve for G2Projective {
     fn to_affine(&self) -> Self::AffineRepr {
         self.into()
     }
+
+    /// Normalizes all the points with a single inversion, using `blst`'s implementation of
+    /// Montgomery's trick.
+    fn batch_normalize(p: &[Self], q: &mut [Self::AffineRepr]) {
+        assert_eq!(p.len(), q.len());
+        if p.is_empty() {
+            return;
+        }
+
+        // `blst` gives wrong results for all the points if any of them is the identity, so the
+        // identities are replaced by the generator, and restored afterwards.
+        let generator = Self::generator();
+        let points: Vec<Self> = p
+            .iter()
+            .map(|p| Self::conditional_select(p, &generator, p.is_identity()))
+            .collect();
+        let ptrs: [*const blst_p2; 2] = [&points[0].0, ptr::null()];
+        unsafe {
+            blst_p2s_to_affine(
+                q.as_mut_ptr() as *mut blst_p2_affine,
+                ptrs.as_ptr(),
+                p.len(),
+            )
+        };
+
+        let identity = G2Affine::identity();
+        for (q, p) in q.iter_mut().zip(p.iter()) {
+            q.conditional_assign(&identity, p.is_identity());
+        }
+    }
 }
 
 impl PrimeCurve for G2Projective {
@@ -741,7 +1163,7 @@ impl UncompressedEncoding for G2Affine {
 #[derive(Clone, Debug)]
 pub struct G2Prepared {
     pub(crate) lines: Vec<blst_fp6>,
-    infinity: bool,
+    pub(crate) infinity: bool,
 }
 
 impl From<G2Affine> for G2Prepared {
@@ -762,6 +1184,30 @@ impl From<G2Affine> for G2Prepared {
     }
 }
 
+impl From<&G2Affine> for G2Prepared {
+    fn from(affine: &G2Affine) -> Self {
+        G2Prepared::from(*affine)
+    }
+}
+
+impl From<G2Projective> for G2Prepared {
+    fn from(p: G2Projective) -> Self {
+        G2Prepared::from(p.to_affine())
+    }
+}
+
+impl From<&G2Projective> for G2Prepared {
+    fn from(p: &G2Projective) -> Self {
+        G2Prepared::from(p.to_affine())
+    }
+}
+
+impl Default for G2Prepared {
+    fn default() -> Self {
+        G2Prepared::from(G2Affine::identity())
+    }
+}
+
 impl G2Prepared {
     pub fn is_identity(&self) -> Choice {
         Choice::from(self.infinity as u8)
@@ -1366,5 +1812,551 @@ mod tests {
         let pippenger = G2Projective::multi_exp(points.as_slice(), scalars.as_slice());
 
         assert_eq!(naive, pippenger);
+
+        assert_eq!(
+            G2Projective::multi_exp(&[], &scalars),
+            G2Projective::identity()
+        );
+        assert_eq!(
+            G2Projective::multi_exp(&points[..1], &scalars[..1]),
+            points[0] * scalars[0]
+        );
+        assert_eq!(
+            G2Projective::multi_exp(&points, &scalars[..5]),
+            G2Projective::multi_exp(&points[..5], &scalars[..5])
+        );
+    }
+
+    #[test]
+    fn test_affine_multi_exp() {
+        let mut rng = XorShiftRng::from_seed([
+            0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06,
+            0xbc, 0xe5,
+        ]);
+
+        for size in [0, 1, 2, 10, 100] {
+            let points: Vec<G2Projective> = (0..size)
+                .map(|i| {
+                    if i % 7 == 3 {
+                        G2Projective::identity()
+                    } else {
+                        G2Projective::random(&mut rng)
+                    }
+                })
+                .collect();
+            let scalars: Vec<Scalar> = (0..size).map(|_| Scalar::random(&mut rng)).collect();
+            let affine: Vec<G2Affine> = points.iter().map(|p| p.to_affine()).collect();
+
+            let naive: G2Projective = points.iter().zip(scalars.iter()).map(|(p, s)| p * s).sum();
+            assert_eq!(
+                G2Affine::multi_exp(&affine, &scalars),
+                naive,
+                "size = {}",
+                size
+            );
+        }
+
+        let points: Vec<G2Affine> = (0..10)
+            .map(|_| G2Projective::random(&mut rng).to_affine())
+            .collect();
+        let scalars: Vec<Scalar> = (0..10).map(|_| Scalar::random(&mut rng)).collect();
+        assert_eq!(
+            G2Affine::multi_exp(&points, &scalars[..5]),
+            G2Affine::multi_exp(&points[..5], &scalars[..5])
+        );
+        assert_eq!(
+            G2Affine::multi_exp(&points[..5], &scalars),
+            G2Affine::multi_exp(&points[..5], &scalars[..5])
+        );
+    }
+
+    #[test]
+    fn test_multi_exp_checked() {
+        let mut rng = XorShiftRng::from_seed([
+            0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06,
+            0xbc, 0xe5,
+        ]);
+
+        let points: Vec<G2Projective> = (0..10).map(|_| G2Projective::random(&mut rng)).collect();
+        let affine: Vec<G2Affine> = points.iter().map(|p| p.to_affine()).collect();
+        let scalars: Vec<Scalar> = (0..10).map(|_| Scalar::random(&mut rng)).collect();
+
+        for n in [1, 2, 10] {
+            let expected = G2Projective::multi_exp(&points[..n], &scalars[..n]);
+            assert_eq!(
+                G2Projective::multi_exp_checked(&points[..n], &scalars[..n]),
+                Ok(expected)
+            );
+            assert_eq!(
+                G2Affine::multi_exp_checked(&affine[..n], &scalars[..n]),
+                Ok(expected)
+            );
+        }
+
+        assert_eq!(
+            G2Projective::multi_exp_checked(&[], &[]),
+            Err(MsmError::Empty)
+        );
+        assert_eq!(G2Affine::multi_exp_checked(&[], &[]), Err(MsmError::Empty));
+
+        for (p, s) in [
+            (0, 1),
+            (1, 0),
+            (0, 10),
+            (10, 0),
+            (5, 10),
+            (10, 5),
+            (9, 10),
+            (10, 9),
+        ] {
+            let err = Err(MsmError::LengthMismatch {
+                points: p,
+                scalars: s,
+            });
+            assert_eq!(
+                G2Projective::multi_exp_checked(&points[..p], &scalars[..s]),
+                err
+            );
+            assert_eq!(
+                G2Affine::multi_exp_checked(&affine[..p], &scalars[..s]),
+                err
+            );
+        }
+    }
+
+    #[test]
+    fn test_batch_multi_exp() {
+        let mut rng = XorShiftRng::from_seed([
+            0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06,
+            0xbc, 0xe5,
+        ]);
+
+        let bases: Vec<G2Projective> = (0..100)
+            .map(|i| {
+                if i % 7 == 3 {
+                    G2Projective::identity()
+                } else {
+                    G2Projective::random(&mut rng)
+                }
+            })
+            .collect();
+        let scalars: Vec<Vec<Scalar>> = [100, 0, 1, 2, 37, 100, 150, 10]
+            .iter()
+            .map(|&n| (0..n).map(|_| Scalar::random(&mut rng)).collect())
+            .collect();
+        let scalars: Vec<&[Scalar]> = scalars.iter().map(|s| s.as_slice()).collect();
+
+        let batch = G2Projective::batch_multi_exp(&bases, &scalars);
+        assert_eq!(batch.len(), scalars.len());
+        for (res, scalars) in batch.iter().zip(scalars.iter()) {
+            let naive: G2Projective = bases.iter().zip(scalars.iter()).map(|(p, s)| p * s).sum();
+            assert_eq!(*res, naive, "n = {}", scalars.len());
+            assert_eq!(
+                *res,
+                G2Projective::multi_exp(&bases, scalars),
+                "n = {}",
+                scalars.len()
+            );
+        }
+
+        assert!(G2Projective::batch_multi_exp(&bases, &[]).is_empty());
+        assert_eq!(
+            G2Projective::batch_multi_exp(&[], &scalars),
+            vec![G2Projective::identity(); scalars.len()]
+        );
+    }
+
+    #[test]
+    fn test_msm_bases() {
+        use crate::G2MsmBases;
+        use core::ops::Range;
+
+        const SIZE: usize = 40;
+        let mut rng = XorShiftRng::from_seed([
+            0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06,
+            0xbc, 0xe5,
+        ]);
+
+        let points: Vec<G2Projective> = (0..SIZE)
+            .map(|i| {
+                if i % 7 == 3 {
+                    G2Projective::identity()
+                } else {
+                    G2Projective::random(&mut rng)
+                }
+            })
+            .collect();
+        let scalars: Vec<Scalar> = (0..SIZE).map(|_| Scalar::random(&mut rng)).collect();
+        let naive = |range: Range<usize>, scalars: &[Scalar]| -> G2Projective {
+            points[range]
+                .iter()
+                .zip(scalars.iter())
+                .map(|(p, s)| p * s)
+                .sum()
+        };
+
+        let plain = G2MsmBases::from_projective(&points);
+        let mut precomputed = G2MsmBases::new(plain.points());
+        precomputed.precompute(4);
+        assert_eq!(plain.len(), SIZE);
+        assert_eq!(plain.wbits(), None);
+        assert_eq!(precomputed.wbits(), Some(4));
+
+        for bases in [&plain, &precomputed] {
+            assert_eq!(bases.msm(&scalars), naive(0..SIZE, &scalars));
+            assert_eq!(bases.msm(&scalars[..5]), naive(0..5, &scalars));
+            assert_eq!(bases.msm(&[]), G2Projective::identity());
+
+            for range in [0..0, 0..1, 3..4, 2..10, 10..SIZE, 0..SIZE] {
+                assert_eq!(
+                    bases.msm_range(range.clone(), &scalars),
+                    naive(range.clone(), &scalars),
+                    "range = {:?}",
+                    range
+                );
+            }
+            assert_eq!(bases.msm_range(5..20, &scalars[..3]), naive(5..8, &scalars));
+        }
+
+        let mut empty = G2MsmBases::new(&[]);
+        assert!(empty.is_empty());
+        assert_eq!(empty.msm(&scalars), G2Projective::identity());
+        empty.precompute(2);
+        assert_eq!(empty.msm(&scalars), G2Projective::identity());
+    }
+
+    #[test]
+    fn test_multi_exp_small_scalars() {
+        let mut rng = XorShiftRng::from_seed([
+            0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06,
+            0xbc, 0xe5,
+        ]);
+
+        let points: Vec<G2Projective> = (0..40)
+            .map(|i| {
+                if i % 7 == 3 {
+                    G2Projective::identity()
+                } else {
+                    G2Projective::random(&mut rng)
+                }
+            })
+            .collect();
+        let words: Vec<u64> = (0..40).map(|_| rng.next_u64()).collect();
+        let bits: Vec<bool> = words.iter().map(|w| w & 1 == 1).collect();
+        let scalars: Vec<Scalar> = (0..40).map(|_| Scalar::random(&mut rng)).collect();
+
+        for n in [0, 1, 2, 40] {
+            let naive: G2Projective = points[..n]
+                .iter()
+                .zip(words.iter())
+                .map(|(p, w)| p * Scalar::from(*w))
+                .sum();
+            assert_eq!(G2Projective::multi_exp_u64(&points[..n], &words), naive);
+
+            let naive: G2Projective = points[..n]
+                .iter()
+                .zip(bits.iter())
+                .filter(|(_, b)| **b)
+                .map(|(p, _)| p)
+                .sum();
+            assert_eq!(G2Projective::multi_exp_bits(&points[..n], &bits), naive);
+
+            for nbits in [1, 7, 8, 9, 64, 200, 255] {
+                // The scalars reduced to their lowest `nbits` bits.
+                let truncated: Vec<Scalar> = scalars
+                    .iter()
+                    .map(|s| {
+                        let mut bytes = s.to_bytes_le();
+                        for (i, byte) in bytes.iter_mut().enumerate() {
+                            for j in 0..8 {
+                                if 8 * i + j >= nbits {
+                                    *byte &= !(1 << j);
+                                }
+                            }
+                        }
+                        Scalar::from_bytes_le(&bytes).unwrap()
+                    })
+                    .collect();
+                assert_eq!(
+                    G2Projective::multi_exp_nbits(&points[..n], &scalars, nbits),
+                    G2Projective::multi_exp(&points[..n], &truncated),
+                    "n = {}, nbits = {}",
+                    n,
+                    nbits
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_psi() {
+        use crate::{fp::MODULUS, scalar::X_ABS};
+
+        let mut rng = XorShiftRng::from_seed([
+            0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06,
+            0xbc, 0xe5,
+        ]);
+
+        // `(p - 1) / d` in little-endian limbs.
+        let p_minus_one_div = |d: u64| {
+            let mut out = MODULUS;
+            out[0] -= 1;
+            let mut rem = 0u128;
+            for limb in out.iter_mut().rev() {
+                let cur = (rem << 64) | u128::from(*limb);
+                *limb = (cur / u128::from(d)) as u64;
+                rem = cur % u128::from(d);
+            }
+            assert_eq!(rem, 0);
+            out
+        };
+
+        // The identities `psi` and `psi2` rely on.
+        let u_plus_one = Fp2::new(Fp::one(), Fp::one());
+        let gamma = FROBENIUS_COEFF_FP6_C1[1];
+        let beta = Fp2(FROBENIUS_COEFF_FP12_C1[1]) * gamma;
+        let omega = FROBENIUS_COEFF_FP6_C1[2];
+        assert_eq!(gamma, u_plus_one.pow_vartime(p_minus_one_div(3)));
+        assert_eq!(beta, u_plus_one.pow_vartime(p_minus_one_div(2)));
+        assert_eq!(Fp2::from(gamma.norm()), omega);
+        assert_eq!(beta.norm(), -Fp::one());
+        assert_eq!(
+            omega,
+            Fp2::from(Fp::from(2u64).pow_vartime(p_minus_one_div(3)))
+        );
+        assert_eq!(omega * FROBENIUS_COEFF_FP6_C2[2], Fp2::one());
+        assert!(bool::from(FROBENIUS_COEFF_FP6_C2[2].c1().is_zero()));
+
+        // ψ is the multiplication by `x = -|x|`.
+        let x = -Scalar::from(X_ABS);
+        for _ in 0..10 {
+            let p = G2Projective::random(&mut rng);
+            assert!(bool::from(p.psi().is_on_curve()));
+            assert_eq!(p.psi(), p * x);
+            assert_eq!(p.psi2(), p.psi().psi());
+            assert_eq!(p.psi2(), p * x.square());
+            // `x^4 - x^2 + 1 = 0` on the subgroup.
+            assert_eq!(p.psi2().psi2(), p.psi2() - p);
+        }
+        assert!(bool::from(G2Projective::identity().psi().is_identity()));
+        assert!(bool::from(G2Projective::identity().psi2().is_identity()));
+    }
+
+    #[test]
+    fn test_mul_by_generator() {
+        let mut rng = XorShiftRng::from_seed([
+            0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06,
+            0xbc, 0xe5,
+        ]);
+
+        let scalars = (0..10).map(|_| Scalar::random(&mut rng)).chain([
+            Scalar::zero(),
+            Scalar::one(),
+            -Scalar::one(),
+        ]);
+        for s in scalars {
+            assert_eq!(
+                G2Projective::mul_by_generator(&s),
+                G2Projective::generator() * s
+            );
+        }
+    }
+
+    #[test]
+    fn test_batch_normalize() {
+        let mut rng = XorShiftRng::from_seed([
+            0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06,
+            0xbc, 0xe5,
+        ]);
+
+        for size in [0, 1, 2, 10, 100] {
+            let points: Vec<G2Projective> = (0..size)
+                .map(|i| {
+                    if i % 7 == 3 {
+                        G2Projective::identity()
+                    } else {
+                        G2Projective::random(&mut rng)
+                    }
+                })
+                .collect();
+            let mut affine = vec![G2Affine::generator(); size];
+            G2Projective::batch_normalize(&points, &mut affine);
+            for (p, q) in points.iter().zip(affine.iter()) {
+                assert_eq!(p.to_affine(), *q);
+            }
+        }
+
+        let mut affine = [G2Affine::generator()];
+        G2Projective::batch_normalize(&[G2Projective::identity()], &mut affine);
+        assert!(bool::from(affine[0].is_identity()));
+    }
+
+    #[cfg(feature = "parallel")]
+    #[test]
+    fn test_batch_from_compressed() {
+        let mut rng = XorShiftRng::from_seed([
+            0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06,
+            0xbc, 0xe5,
+        ]);
+
+        // A point on the curve but outside the subgroup.
+        let off_subgroup = loop {
+            let mut bytes = [0u8; COMPRESSED_SIZE];
+            rng.fill_bytes(&mut bytes);
+            bytes[0] = (bytes[0] & 0x1f) | 0x80;
+            let p = G2Affine::from_compressed_unchecked(&bytes);
+            if bool::from(p.is_some()) {
+                assert_eq!(p.unwrap().is_torsion_free().unwrap_u8(), 0);
+                break bytes;
+            }
+        };
+
+        let mut not_on_curve = loop {
+            let mut bytes = [0u8; COMPRESSED_SIZE];
+            rng.fill_bytes(&mut bytes);
+            bytes[0] = (bytes[0] & 0x1f) | 0x80;
+            if bool::from(G2Affine::from_compressed_unchecked(&bytes).is_none()) {
+                break bytes;
+            }
+        };
+        not_on_curve[0] |= 0x20;
+
+        let mut bytes: Vec<[u8; COMPRESSED_SIZE]> = (0..20)
+            .map(|_| G2Projective::random(&mut rng).to_affine().to_compressed())
+            .collect();
+        bytes[3] = G2Affine::identity().to_compressed();
+        bytes[5] = off_subgroup;
+        bytes[11] = not_on_curve;
+        bytes[17][0] &= 0x7f;
+
+        let points = G2Affine::batch_from_compressed(&bytes);
+        assert_eq!(points.len(), bytes.len());
+        for (p, b) in points.iter().zip(bytes.iter()) {
+            let expected = G2Affine::from_compressed(b);
+            assert_eq!(p.is_some().unwrap_u8(), expected.is_some().unwrap_u8());
+            if bool::from(expected.is_some()) {
+                assert_eq!(p.unwrap(), expected.unwrap());
+            }
+        }
+        let valid = points.iter().filter(|p| bool::from(p.is_some())).count();
+        assert_eq!(valid, bytes.len() - 3);
+
+        assert!(G2Affine::batch_from_compressed(&[]).is_empty());
+    }
+
+    // The RFC 9380 vectors are checked in `tests/hash_to_curve.rs`.
+    #[test]
+    fn test_encode_to_curve() {
+        const DST: &[u8] = b"QUUX-V01-CS02-with-BLS12381G2_XMD:SHA-256_SSWU_NU_";
+
+        let p = G2Projective::encode_to_curve(b"abc", DST, &[]);
+        assert!(bool::from(p.is_on_curve()));
+        assert!(bool::from(p.to_affine().is_torsion_free()));
+        assert_ne!(p, G2Projective::hash_to_curve(b"abc", DST, &[]));
+
+        // `aug` is prepended to the message.
+        assert_eq!(G2Projective::encode_to_curve(b"c", DST, b"ab"), p);
+    }
+
+    #[test]
+    fn test_map_to_curve() {
+        let mut rng = XorShiftRng::from_seed([
+            0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06,
+            0xbc, 0xe5,
+        ]);
+
+        for _ in 0..10 {
+            let u = Fp2::random(&mut rng);
+            let p = G2Projective::map_to_curve(&u);
+            assert!(bool::from(p.is_on_curve()));
+            // The sign of `u` only picks the sign of `y`.
+            assert_eq!(G2Projective::map_to_curve(&-u), -p);
+        }
+
+        // The exceptional case of the simplified SWU map.
+        assert!(bool::from(
+            G2Projective::map_to_curve(&Fp2::zero()).is_on_curve()
+        ));
+    }
+
+    #[test]
+    fn test_hash_to_curve_with() {
+        use crate::hash_to_field::{ExpandMsgXmdSha256, ExpandMsgXofShake256};
+
+        const DST: &[u8] = b"QUUX-V01-CS02-with-BLS12381G2_XMD:SHA-256_SSWU_RO_";
+
+        for (msg, aug) in [("", ""), ("abc", ""), ("abc", "aug"), ("", "aug")].iter() {
+            assert_eq!(
+                G2Projective::hash_to_curve_with::<ExpandMsgXmdSha256>(
+                    msg.as_bytes(),
+                    DST,
+                    aug.as_bytes()
+                ),
+                G2Projective::hash_to_curve(msg.as_bytes(), DST, aug.as_bytes())
+            );
+        }
+
+        // RFC 9380 has no `XOF:SHAKE-256` vectors for BLS12-381. These match
+        // `G2Projective::hash::<ExpandMsgXof<Shake256>>` of `bls12_381_plus` 0.8.18, listing
+        // `x = x_0 + x_1 * u` as `x_0, x_1`.
+        const XOF_DST: &[u8] = b"QUUX-V01-CS02-with-BLS12381G2_XOF:SHAKE-256_SSWU_RO_";
+        let expected = [
+            (
+                "",
+                [
+                    "0761c521c8a9859203f8e9a413b41d2ed1a6c67d42b2a7f0db27998c006a45d9b812b54d7d9710c02394cd79cc57b34b",
+                    "03e4c7e96bf7ebc4c0a97b9d6cba070f2f5a1deb5f944c3eafb150906788e43843f01b87a8ef8a03bd687c11ee05d79d",
+                    "0f4fd9febee105a773c78931d74788fa9eafb17e53694631b61f9f909dcbb25af9613a08d0174688a9921375f6e43bc6",
+                    "0f1d3b4ab7e7de676307f7b6f497b8360b7dfb8c0ce0e54cac95e3508b6f20d172f82f34ae5910aaf11b48e74e28fded",
+                ],
+            ),
+            (
+                "abc",
+                [
+                    "065348f781a1cc525be4aa75b7836d85dcbfef7d54ca79b87690143e3dced01377a240af9f8f6d907ead3ac0b757a242",
+                    "05f43e999fd0e6f16e1190db843219036bd9d5f653f150cce65b1a4a78eb3bc5974cf29b3a8e34b6b1e854c2308d02f6",
+                    "17917e7374d772e887f29549beb4fe8cbdf5b33e31cd829c3d02761417b6fd0c3d6ae687423fd5a71dd219fc256ccac5",
+                    "0aba8206c8845dae54b9c13f3eab4bc4468f84e0106f303ad63ddae87a8d940ab6320f63bece9bc37c3196698784d244",
+                ],
+            ),
+        ];
+
+        for (msg, expected) in expected.iter() {
+            let p = G2Projective::hash_to_curve_with::<ExpandMsgXofShake256>(
+                msg.as_bytes(),
+                XOF_DST,
+                &[],
+            );
+            assert_eq!(
+                hex::encode(p.to_affine().to_uncompressed()),
+                [expected[1], expected[0], expected[3], expected[2]].concat()
+            );
+            assert!(bool::from(p.to_affine().is_torsion_free()));
+        }
+    }
+
+    #[test]
+    fn test_clear_cofactor() {
+        let mut rng = XorShiftRng::from_seed([
+            0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06,
+            0xbc, 0xe5,
+        ]);
+
+        // `h_eff mod q`.
+        let h_eff =
+            Scalar::from_u64s_le(&[0xa402_0004_0001_ffff, 0xb116_9004_0006_9009, 0x2, 0]).unwrap();
+
+        for _ in 0..10 {
+            let p = G2Projective::map_to_curve(&Fp2::random(&mut rng));
+            assert!(bool::from(p.is_on_curve()));
+            assert!(!bool::from(p.to_affine().is_torsion_free()));
+            assert!(bool::from(p.clear_cofactor().to_affine().is_torsion_free()));
+
+            let p = G2Projective::random(&mut rng);
+            assert_eq!(p.clear_cofactor(), p * h_eff);
+        }
+        assert!(bool::from(
+            G2Projective::identity().clear_cofactor().is_identity()
+        ));
     }
 }
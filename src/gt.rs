@@ -1,5 +1,6 @@
 use core::{
     borrow::Borrow,
+    convert::TryFrom,
     fmt,
     iter::Sum,
     ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign},
@@ -9,7 +10,7 @@ use blst::*;
 use ff::Field;
 use group::Group;
 use rand_core::RngCore;
-use subtle::{Choice, ConstantTimeEq};
+use subtle::{Choice, ConditionallySelectable, ConstantTimeEq};
 
 use crate::{fp::Fp, fp12::Fp12, fp2::Fp2, fp6::Fp6, traits::Compress, Scalar};
 
@@ -28,9 +29,31 @@ impl fmt::Display for Gt {
     }
 }
 
-impl From<Fp12> for Gt {
-    fn from(fp12: Fp12) -> Self {
-        Gt(fp12)
+/// The error returned when converting an element which is not on the curve or not in the prime
+/// order subgroup.
+#[derive(Debug, Clone)]
+pub struct NotInSubgroupError;
+
+impl fmt::Display for NotInSubgroupError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Not in subgroup")
+    }
+}
+
+impl std::error::Error for NotInSubgroupError {}
+
+impl TryFrom<Fp12> for Gt {
+    type Error = NotInSubgroupError;
+
+    /// Fails if `fp12` is not in the order `q` subgroup of `Fp12`, on which the arithmetic of
+    /// `Gt` relies.
+    fn try_from(fp12: Fp12) -> Result<Self, Self::Error> {
+        let gt = Gt(fp12);
+        if gt.is_in_subgroup() {
+            Ok(gt)
+        } else {
+            Err(NotInSubgroupError)
+        }
     }
 }
 
@@ -49,6 +72,12 @@ impl PartialEq for Gt {
     }
 }
 
+impl ConditionallySelectable for Gt {
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        Gt(Fp12::conditional_select(&a.0, &b.0, choice))
+    }
+}
+
 impl Neg for &Gt {
     type Output = Gt;
 
@@ -92,32 +121,36 @@ impl Sub<&Gt> for &Gt {
 impl Mul<&Scalar> for &Gt {
     type Output = Gt;
 
+    /// Constant-time multiplication, see [`Gt::mul_vartime`] for public scalars.
     #[allow(clippy::suspicious_arithmetic_impl)]
     fn mul(self, scalar: &Scalar) -> Self::Output {
+        let table = self.frobenius_table();
+        let digits = scalar.to_x_abs_digits();
+
         let mut acc = Gt::identity();
+        for i in (0..64).rev() {
+            acc = acc.cyclotomic_double();
 
-        // This is a simple double-and-add implementation of group element
-        // multiplication, moving from most significant to least
-        // significant bit of the scalar.
-        //
-        // We skip the leading bit because it's always unset for Fq
-        // elements.
-        for bit in scalar
-            .to_bytes_be()
-            .iter()
-            .flat_map(|byte| (0..8).rev().map(move |i| (byte >> i) & 1 == 1))
-            .skip(1)
-        {
-            acc = acc.double();
-            if bit {
-                acc += self;
+            let index = frobenius_table_index(&digits, i);
+            let mut term = Gt::identity();
+            for (j, entry) in table.iter().enumerate() {
+                term.conditional_assign(entry, (j as u8).ct_eq(&index));
             }
+            acc += term;
         }
 
         acc
     }
 }
 
+/// Returns the index in [`Gt::frobenius_table`] of the `i`-th bits of the four digits.
+fn frobenius_table_index(digits: &[u64; 4], i: usize) -> u8 {
+    digits
+        .iter()
+        .enumerate()
+        .fold(0, |index, (j, d)| index | (((d >> i) & 1) as u8) << j)
+}
+
 impl AddAssign<&Gt> for Gt {
     #[inline]
     fn add_assign(&mut self, rhs: &Gt) {
@@ -300,6 +333,7 @@ impl Group for Gt {
         self.0.ct_eq(&Self::identity().0)
     }
 
+    #[allow(unused_attributes)]
     #[must_use]
     fn double(&self) -> Self {
         Gt(self.0.square())
@@ -318,15 +352,118 @@ impl Gt {
         // "On Compressible Pairings and Their Computation" by Naehrig et al.
         let mut c0 = self.0.c0();
 
-        c0.0.fp2[0] = (c0.c0() + Fp2::from(1)).0;
+        c0.0.fp2[0] = (c0.c0() + Fp2::from(1u64)).0;
         let b = c0 * self.0.c1().invert().unwrap();
 
         Some(GtCompressed(b))
     }
 
-    fn is_in_subgroup(&self) -> bool {
+    pub(crate) fn is_in_subgroup(&self) -> bool {
         unsafe { blst_fp12_in_group(&(self.0).0) }
     }
+
+    /// Doubles this element with a cyclotomic squaring, which is only correct for elements of
+    /// the cyclotomic subgroup.
+    fn cyclotomic_double(&self) -> Gt {
+        let mut out = blst_fp12::default();
+        unsafe { blst_fp12_cyclotomic_sqr(&mut out, &(self.0).0) };
+        Gt(Fp12(out))
+    }
+
+    /// Returns the sums of the subsets of `[g, -φ(g), φ²(g), -φ³(g)]`, where `φ` is the Frobenius
+    /// map, indexed by the bitmask of the subset.
+    ///
+    /// `φ` acts on $\mathbb{G}_T$ as multiplication by `p = x (mod q)`. Writing a scalar in base
+    /// `|x| = -x` as `d0 + d1 |x| + d2 |x|^2 + d3 |x|^3`, its product with `g` is the sum of the
+    /// products of the 64-bit digits with these four elements.
+    fn frobenius_table(&self) -> [Gt; 16] {
+        let mut bases = [*self; 4];
+        for (i, base) in bases.iter_mut().enumerate().skip(1) {
+            base.0.frobenius_map(i);
+            if i % 2 == 1 {
+                base.0.conjugate();
+            }
+        }
+
+        let mut table = [Gt::identity(); 16];
+        for i in 1..16 {
+            table[i] = table[i & (i - 1)] + bases[i.trailing_zeros() as usize];
+        }
+        table
+    }
+
+    /// Multiplies `self` by a public `scalar`, in variable time. Like the constant-time
+    /// multiplication, it uses cyclotomic squarings and the Frobenius decomposition of `scalar`,
+    /// but it skips the leading zero bits and the additions of the identity.
+    pub fn mul_vartime(&self, scalar: &Scalar) -> Gt {
+        let table = self.frobenius_table();
+        let digits = scalar.to_x_abs_digits();
+        let bits = digits
+            .iter()
+            .map(|d| 64 - d.leading_zeros() as usize)
+            .max()
+            .unwrap();
+
+        let mut acc = Gt::identity();
+        for i in (0..bits).rev() {
+            acc = acc.cyclotomic_double();
+
+            let index = frobenius_table_index(&digits, i);
+            if index != 0 {
+                acc += table[index as usize];
+            }
+        }
+
+        acc
+    }
+
+    /// Perform a multi-exponentiation, aka "multi-scalar-multiplication" (MSM), using Pippenger's
+    /// bucket algorithm with cyclotomic squarings. Extra points or scalars are ignored.
+    pub fn multi_exp(points: &[Gt], scalars: &[Scalar]) -> Gt {
+        let n = points.len().min(scalars.len());
+        if n == 0 {
+            return Gt::identity();
+        }
+
+        // The window size, roughly `ln(n) + 2`, as in most Pippenger implementations.
+        let c = if n < 32 {
+            3
+        } else {
+            (usize::BITS - n.leading_zeros()) as usize * 69 / 100 + 2
+        };
+        let scalars: Vec<[u8; 32]> = scalars[..n].iter().map(|s| s.to_bytes_le()).collect();
+        let window = |bytes: &[u8; 32], start: usize| -> usize {
+            (start..(start + c).min(255))
+                .map(|bit| ((bytes[bit / 8] >> (bit % 8)) & 1) as usize)
+                .rev()
+                .fold(0, |acc, bit| (acc << 1) | bit)
+        };
+
+        let mut acc = Gt::identity();
+        let mut buckets = vec![Gt::identity(); (1 << c) - 1];
+        for start in (0..255).step_by(c).rev() {
+            for _ in 0..c {
+                acc = acc.cyclotomic_double();
+            }
+
+            buckets.iter_mut().for_each(|b| *b = Gt::identity());
+            for (point, bytes) in points.iter().zip(scalars.iter()) {
+                let digit = window(bytes, start);
+                if digit != 0 {
+                    buckets[digit - 1] += point;
+                }
+            }
+
+            // Sum `digit * buckets[digit - 1]` with running sums.
+            let mut running_sum = Gt::identity();
+            for bucket in buckets.iter().rev() {
+                running_sum += bucket;
+                acc += running_sum;
+            }
+        }
+
+        acc
+    }
 }
 
 impl GtCompressed {
@@ -447,6 +584,20 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_gt_try_from() {
+        let mut rng = XorShiftRng::from_seed([
+            0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06,
+            0xbc, 0xe5,
+        ]);
+        for _ in 0..10 {
+            let g = Gt::random(&mut rng);
+            assert_eq!(Gt::try_from(Fp12::from(g)).unwrap(), g);
+            assert!(Gt::try_from(Fp12::random(&mut rng)).is_err());
+        }
+        assert_eq!(Gt::try_from(Fp12::one()).unwrap(), Gt::identity());
+    }
+
     #[test]
     fn test_gt_unitary() {
         let g = G1Affine::generator();
@@ -587,4 +738,82 @@ mod tests {
         let a = crate::pairing(&p, &q);
         assert!(a.is_in_subgroup());
     }
+
+    #[test]
+    fn test_gt_multi_exp() {
+        let mut rng = XorShiftRng::from_seed([
+            0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06,
+            0xbc, 0xe5,
+        ]);
+
+        let points: Vec<Gt> = (0..40)
+            .map(|i| {
+                if i % 7 == 3 {
+                    Gt::identity()
+                } else {
+                    Gt::random(&mut rng)
+                }
+            })
+            .collect();
+        let mut scalars: Vec<Scalar> = (0..40).map(|_| Scalar::random(&mut rng)).collect();
+        scalars[5] = Scalar::zero();
+        scalars[6] = Scalar::one();
+        scalars[7] = -Scalar::one();
+
+        for n in [0, 1, 2, 10, 33, 40] {
+            let naive: Gt = points[..n]
+                .iter()
+                .zip(scalars.iter())
+                .map(|(p, s)| p * s)
+                .sum();
+            assert_eq!(Gt::multi_exp(&points[..n], &scalars), naive, "n = {}", n);
+        }
+        assert_eq!(
+            Gt::multi_exp(&points, &scalars[..5]),
+            Gt::multi_exp(&points[..5], &scalars[..5])
+        );
+    }
+
+    /// The double-and-add multiplication, as a reference.
+    fn mul_double_and_add(p: &Gt, scalar: &Scalar) -> Gt {
+        let mut acc = Gt::identity();
+        for bit in scalar
+            .to_bytes_be()
+            .iter()
+            .flat_map(|byte| (0..8).rev().map(move |i| (byte >> i) & 1 == 1))
+            .skip(1)
+        {
+            acc = acc.double();
+            if bit {
+                acc += p;
+            }
+        }
+        acc
+    }
+
+    #[test]
+    fn test_gt_mul() {
+        let mut rng = XorShiftRng::from_seed([
+            0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06,
+            0xbc, 0xe5,
+        ]);
+
+        let points: Vec<Gt> = (0..10)
+            .map(|_| Gt::random(&mut rng))
+            .chain([Gt::identity(), Gt::generator()])
+            .collect();
+        for p in points {
+            let scalars = (0..5).map(|_| Scalar::random(&mut rng)).chain([
+                Scalar::zero(),
+                Scalar::one(),
+                -Scalar::one(),
+                Scalar::from(0xd201_0000_0001_0000u64),
+            ]);
+            for s in scalars {
+                let expected = mul_double_and_add(&p, &s);
+                assert_eq!(p * s, expected);
+                assert_eq!(p.mul_vartime(&s), expected);
+            }
+        }
+    }
 }
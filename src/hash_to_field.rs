@@ -0,0 +1,258 @@
+//! The `expand_message` functions of [RFC 9380](https://www.rfc-editor.org/rfc/rfc9380.html#section-5.3),
+//! which stretch a message into uniformly random bytes for `hash_to_field`.
+
+use blst::blst_expand_message_xmd;
+use sha3::{
+    digest::{ExtendableOutput, Update, XofReader},
+    Shake256,
+};
+
+/// The longest output of `expand_message_xmd` with SHA-256.
+pub(crate) const MAX_XMD_LEN: usize = 255 * 32;
+
+/// The longest output of `expand_message_xof`.
+pub(crate) const MAX_XOF_LEN: usize = u16::MAX as usize;
+
+/// Domain separation tags longer than this are hashed before use.
+const MAX_DST_LEN: usize = 255;
+
+/// The length of a hashed long domain separation tag in `expand_message_xof`, `ceil(2 * k / 8)`
+/// for the 128-bit security level of BLS12-381.
+const XOF_LONG_DST_LEN: usize = 32;
+
+/// An `expand_message` function, which stretches a message into uniformly random bytes.
+pub trait ExpandMsg {
+    /// The most bytes `expand_message` can produce.
+    const MAX_LEN: usize;
+
+    /// Expands `msg` into `len` bytes with the domain separation tag `dst`.
+    ///
+    /// Panics if `len` is more than `MAX_LEN`.
+    fn expand_message(msg: &[u8], dst: &[u8], len: usize) -> Vec<u8>;
+}
+
+/// `expand_message_xmd` with SHA-256, as used by the `XMD:SHA-256` suites.
+#[derive(Debug, Clone, Copy)]
+pub struct ExpandMsgXmdSha256;
+
+impl ExpandMsg for ExpandMsgXmdSha256 {
+    const MAX_LEN: usize = MAX_XMD_LEN;
+
+    fn expand_message(msg: &[u8], dst: &[u8], len: usize) -> Vec<u8> {
+        expand_message_xmd(msg, dst, len)
+    }
+}
+
+/// `expand_message_xof` with SHAKE256, as used by the `XOF:SHAKE-256` suites.
+#[derive(Debug, Clone, Copy)]
+pub struct ExpandMsgXofShake256;
+
+impl ExpandMsg for ExpandMsgXofShake256 {
+    const MAX_LEN: usize = MAX_XOF_LEN;
+
+    fn expand_message(msg: &[u8], dst: &[u8], len: usize) -> Vec<u8> {
+        expand_message_xof(msg, dst, len)
+    }
+}
+
+/// `expand_message_xmd` with SHA-256, producing `len` bytes.
+///
+/// Panics if `len` is more than [`MAX_XMD_LEN`].
+pub(crate) fn expand_message_xmd(msg: &[u8], dst: &[u8], len: usize) -> Vec<u8> {
+    assert!(
+        len <= MAX_XMD_LEN,
+        "expand_message_xmd can produce at most {} bytes",
+        MAX_XMD_LEN
+    );
+    // `blst` always writes at least one block.
+    if len == 0 {
+        return Vec::new();
+    }
+
+    let mut out = vec![0u8; len];
+    unsafe {
+        blst_expand_message_xmd(
+            out.as_mut_ptr(),
+            len,
+            msg.as_ptr(),
+            msg.len(),
+            dst.as_ptr(),
+            dst.len(),
+        );
+    }
+    out
+}
+
+/// `expand_message_xof` with SHAKE256, producing `len` bytes.
+///
+/// Panics if `len` is more than [`MAX_XOF_LEN`].
+pub(crate) fn expand_message_xof(msg: &[u8], dst: &[u8], len: usize) -> Vec<u8> {
+    expand_message_xof_with::<Shake256>(msg, dst, len)
+}
+
+/// `expand_message_xof` with the extendable-output function `H`, which must provide 128-bit
+/// security like BLS12-381.
+fn expand_message_xof_with<H: Default + Update + ExtendableOutput>(
+    msg: &[u8],
+    dst: &[u8],
+    len: usize,
+) -> Vec<u8> {
+    assert!(
+        len <= MAX_XOF_LEN,
+        "expand_message_xof can produce at most {} bytes",
+        MAX_XOF_LEN
+    );
+
+    let mut long_dst = [0u8; XOF_LONG_DST_LEN];
+    let dst = if dst.len() > MAX_DST_LEN {
+        let mut hasher = H::default();
+        hasher.update(b"H2C-OVERSIZE-DST-");
+        hasher.update(dst);
+        hasher.finalize_xof().read(&mut long_dst);
+        &long_dst[..]
+    } else {
+        dst
+    };
+
+    let mut hasher = H::default();
+    hasher.update(msg);
+    hasher.update(&(len as u16).to_be_bytes());
+    hasher.update(dst);
+    hasher.update(&[dst.len() as u8]);
+
+    let mut out = vec![0u8; len];
+    hasher.finalize_xof().read(&mut out);
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use sha3::Shake128;
+
+    fn messages() -> [String; 5] {
+        [
+            String::new(),
+            "abc".to_string(),
+            "abcdef0123456789".to_string(),
+            format!("q128_{}", "q".repeat(128)),
+            format!("a512_{}", "a".repeat(512)),
+        ]
+    }
+
+    fn check(
+        expand: fn(&[u8], &[u8], usize) -> Vec<u8>,
+        dst: &[u8],
+        expected_short: [&str; 5],
+        expected_long: [&str; 5],
+    ) {
+        for (msg, expected) in messages().iter().zip(expected_short.iter()) {
+            assert_eq!(hex::encode(expand(msg.as_bytes(), dst, 0x20)), *expected);
+        }
+        for (msg, expected) in messages().iter().zip(expected_long.iter()) {
+            assert_eq!(hex::encode(expand(msg.as_bytes(), dst, 0x80)), *expected);
+        }
+    }
+
+    // Test vectors from RFC 9380, Appendix K.1.
+    #[test]
+    fn test_expand_message_xmd() {
+        check(
+            expand_message_xmd,
+            b"QUUX-V01-CS02-with-expander-SHA256-128",
+            [
+                "68a985b87eb6b46952128911f2a4412bbc302a9d759667f87f7a21d803f07235",
+                "d8ccab23b5985ccea865c6c97b6e5b8350e794e603b4b97902f53a8a0d605615",
+                "eff31487c770a893cfb36f912fbfcbff40d5661771ca4b2cb4eafe524333f5c1",
+                "b23a1d2b4d97b2ef7785562a7e8bac7eed54ed6e97e29aa51bfe3f12ddad1ff9",
+                "4623227bcc01293b8c130bf771da8c298dede7383243dc0993d2d94823958c4c",
+            ],
+            [
+                "af84c27ccfd45d41914fdff5df25293e221afc53d8ad2ac06d5e3e29485dadbee0d121587713a3e0dd4d5e69e93eb7cd4f5df4cd103e188cf60cb02edc3edf18eda8576c412b18ffb658e3dd6ec849469b979d444cf7b26911a08e63cf31f9dcc541708d3491184472c2c29bb749d4286b004ceb5ee6b9a7fa5b646c993f0ced",
+                "abba86a6129e366fc877aab32fc4ffc70120d8996c88aee2fe4b32d6c7b6437a647e6c3163d40b76a73cf6a5674ef1d890f95b664ee0afa5359a5c4e07985635bbecbac65d747d3d2da7ec2b8221b17b0ca9dc8a1ac1c07ea6a1e60583e2cb00058e77b7b72a298425cd1b941ad4ec65e8afc50303a22c0f99b0509b4c895f40",
+                "ef904a29bffc4cf9ee82832451c946ac3c8f8058ae97d8d629831a74c6572bd9ebd0df635cd1f208e2038e760c4994984ce73f0d55ea9f22af83ba4734569d4bc95e18350f740c07eef653cbb9f87910d833751825f0ebefa1abe5420bb52be14cf489b37fe1a72f7de2d10be453b2c9d9eb20c7e3f6edc5a60629178d9478df",
+                "80be107d0884f0d881bb460322f0443d38bd222db8bd0b0a5312a6fedb49c1bbd88fd75d8b9a09486c60123dfa1d73c1cc3169761b17476d3c6b7cbbd727acd0e2c942f4dd96ae3da5de368d26b32286e32de7e5a8cb2949f866a0b80c58116b29fa7fabb3ea7d520ee603e0c25bcaf0b9a5e92ec6a1fe4e0391d1cdbce8c68a",
+                "546aff5444b5b79aa6148bd81728704c32decb73a3ba76e9e75885cad9def1d06d6792f8a7d12794e90efed817d96920d728896a4510864370c207f99bd4a608ea121700ef01ed879745ee3e4ceef777eda6d9e5e38b90c86ea6fb0b36504ba4a45d22e86f6db5dd43d98a294bebb9125d5b794e9d2a81181066eb954966a487",
+            ],
+        );
+    }
+
+    // Test vectors from RFC 9380, Appendix K.2, with a domain separation tag longer than 255 bytes.
+    #[test]
+    fn test_expand_message_xmd_long_dst() {
+        let dst = format!(
+            "QUUX-V01-CS02-with-expander-SHA256-128-long-DST-{}",
+            "1".repeat(208)
+        );
+        check(
+            expand_message_xmd,
+            dst.as_bytes(),
+            [
+                "e8dc0c8b686b7ef2074086fbdd2f30e3f8bfbd3bdf177f73f04b97ce618a3ed3",
+                "52dbf4f36cf560fca57dedec2ad924ee9c266341d8f3d6afe5171733b16bbb12",
+                "35387dcf22618f3728e6c686490f8b431f76550b0b2c61cbc1ce7001536f4521",
+                "01b637612bb18e840028be900a833a74414140dde0c4754c198532c3a0ba42bc",
+                "20cce7033cabc5460743180be6fa8aac5a103f56d481cf369a8accc0c374431b",
+            ],
+            [
+                "14604d85432c68b757e485c8894db3117992fc57e0e136f71ad987f789a0abc287c47876978e2388a02af86b1e8d1342e5ce4f7aaa07a87321e691f6fba7e0072eecc1218aebb89fb14a0662322d5edbd873f0eb35260145cd4e64f748c5dfe60567e126604bcab1a3ee2dc0778102ae8a5cfd1429ebc0fa6bf1a53c36f55dfc",
+                "1a30a5e36fbdb87077552b9d18b9f0aee16e80181d5b951d0471d55b66684914aef87dbb3626eaabf5ded8cd0686567e503853e5c84c259ba0efc37f71c839da2129fe81afdaec7fbdc0ccd4c794727a17c0d20ff0ea55e1389d6982d1241cb8d165762dbc39fb0cee4474d2cbbd468a835ae5b2f20e4f959f56ab24cd6fe267",
+                "d2ecef3635d2397f34a9f86438d772db19ffe9924e28a1caf6f1c8f15603d4028f40891044e5c7e39ebb9b31339979ff33a4249206f67d4a1e7c765410bcd249ad78d407e303675918f20f26ce6d7027ed3774512ef5b00d816e51bfcc96c3539601fa48ef1c07e494bdc37054ba96ecb9dbd666417e3de289d4f424f502a982",
+                "ed6e8c036df90111410431431a232d41a32c86e296c05d426e5f44e75b9a50d335b2412bc6c91e0a6dc131de09c43110d9180d0a70f0d6289cb4e43b05f7ee5e9b3f42a1fad0f31bac6a625b3b5c50e3a83316783b649e5ecc9d3b1d9471cb5024b7ccf40d41d1751a04ca0356548bc6e703fca02ab521b505e8e45600508d32",
+                "78b53f2413f3c688f07732c10e5ced29a17c6a16f717179ffbe38d92d6c9ec296502eb9889af83a1928cd162e845b0d3c5424e83280fed3d10cffb2f8431f14e7a23f4c68819d40617589e4c41169d0b56e0e3535be1fd71fbb08bb70c5b5ffed953d6c14bf7618b35fc1f4c4b30538236b4b08c9fbf90462447a8ada60be495",
+            ],
+        );
+    }
+
+    // Test vectors from RFC 9380, Appendix K.6.
+    #[test]
+    fn test_expand_message_xof() {
+        check(
+            expand_message_xof,
+            b"QUUX-V01-CS02-with-expander-SHAKE256",
+            [
+                "2ffc05c48ed32b95d72e807f6eab9f7530dd1c2f013914c8fed38c5ccc15ad76",
+                "b39e493867e2767216792abce1f2676c197c0692aed061560ead251821808e07",
+                "245389cf44a13f0e70af8665fe5337ec2dcd138890bb7901c4ad9cfceb054b65",
+                "719b3911821e6428a5ed9b8e600f2866bcf23c8f0515e52d6c6c019a03f16f0e",
+                "9181ead5220b1963f1b5951f35547a5ea86a820562287d6ca4723633d17ccbbc",
+            ],
+            [
+                "7a1361d2d7d82d79e035b8880c5a3c86c5afa719478c007d96e6c88737a3f631dd74a2c88df79a4cb5e5d9f7504957c70d669ec6bfedc31e01e2bacc4ff3fdf9b6a00b17cc18d9d72ace7d6b81c2e481b4f73f34f9a7505dccbe8f5485f3d20c5409b0310093d5d6492dea4e18aa6979c23c8ea5de01582e9689612afbb353df",
+                "a54303e6b172909783353ab05ef08dd435a558c3197db0c132134649708e0b9b4e34fb99b92a9e9e28fc1f1d8860d85897a8e021e6382f3eea10577f968ff6df6c45fe624ce65ca25932f679a42a404bc3681efe03fcd45ef73bb3a8f79ba784f80f55ea8a3c367408f30381299617f50c8cf8fbb21d0f1e1d70b0131a7b6fbe",
+                "e42e4d9538a189316e3154b821c1bafb390f78b2f010ea404e6ac063deb8c0852fcd412e098e231e43427bd2be1330bb47b4039ad57b30ae1fc94e34993b162ff4d695e42d59d9777ea18d3848d9d336c25d2acb93adcad009bcfb9cde12286df267ada283063de0bb1505565b2eb6c90e31c48798ecdc71a71756a9110ff373",
+                "4ac054dda0a38a65d0ecf7afd3c2812300027c8789655e47aecf1ecc1a2426b17444c7482c99e5907afd9c25b991990490bb9c686f43e79b4471a23a703d4b02f23c669737a886a7ec28bddb92c3a98de63ebf878aa363a501a60055c048bea11840c4717beae7eee28c3cfa42857b3d130188571943a7bd747de831bd6444e0",
+                "09afc76d51c2cccbc129c2315df66c2be7295a231203b8ab2dd7f95c2772c68e500bc72e20c602abc9964663b7a03a389be128c56971ce81001a0b875e7fd17822db9d69792ddf6a23a151bf470079c518279aef3e75611f8f828994a9988f4a8a256ddb8bae161e658d5a2a09bcfe839c6396dc06ee5c8ff3c22d3b1f9deb7e",
+            ],
+        );
+    }
+
+    // Test vectors from RFC 9380, Appendix K.5. The RFC has no long domain separation tag
+    // vectors for SHAKE256, so these exercise the same code with SHAKE128.
+    #[test]
+    fn test_expand_message_xof_long_dst() {
+        let dst = format!(
+            "QUUX-V01-CS02-with-expander-SHAKE128-long-DST-{}",
+            "1".repeat(210)
+        );
+        check(
+            expand_message_xof_with::<Shake128>,
+            dst.as_bytes(),
+            [
+                "827c6216330a122352312bccc0c8d6e7a146c5257a776dbd9ad9d75cd880fc53",
+                "690c8d82c7213b4282c6cb41c00e31ea1d3e2005f93ad19bbf6da40f15790c5c",
+                "979e3a15064afbbcf99f62cc09fa9c85028afcf3f825eb0711894dcfc2f57057",
+                "c5a9220962d9edc212c063f4f65b609755a1ed96e62f9db5d1fd6adb5a8dc52b",
+                "f7b96a5901af5d78ce1d071d9c383cac66a1dfadb508300ec6aeaea0d62d5d62",
+            ],
+            [
+                "3890dbab00a2830be398524b71c2713bbef5f4884ac2e6f070b092effdb19208c7df943dc5dcbaee3094a78c267ef276632ee2c8ea0c05363c94b6348500fae4208345dd3475fe0c834c2beac7fa7bc181692fb728c0a53d809fc8111495222ce0f38468b11becb15b32060218e285c57a60162c2c8bb5b6bded13973cd41819",
+                "41b7ffa7a301b5c1441495ebb9774e2a53dbbf4e54b9a1af6a20fd41eafd69ef7b9418599c5545b1ee422f363642b01d4a53449313f68da3e49dddb9cd25b97465170537d45dcbdf92391b5bdff344db4bd06311a05bca7dcd360b6caec849c299133e5c9194f4e15e3e23cfaab4003fab776f6ac0bfae9144c6e2e1c62e7d57",
+                "55317e4a21318472cd2290c3082957e1242241d9e0d04f47026f03401643131401071f01aa03038b2783e795bdfa8a3541c194ad5de7cb9c225133e24af6c86e748deb52e560569bd54ef4dac03465111a3a44b0ea490fb36777ff8ea9f1a8a3e8e0de3cf0880b4b2f8dd37d3a85a8b82375aee4fa0e909f9763319b55778e71",
+                "19fdd2639f082e31c77717ac9bb032a22ff0958382b2dbb39020cdc78f0da43305414806abf9a561cb2d0067eb2f7bc544482f75623438ed4b4e39dd9e6e2909dd858bd8f1d57cd0fce2d3150d90aa67b4498bdf2df98c0100dd1a173436ba5d0df6be1defb0b2ce55ccd2f4fc05eb7cb2c019c35d5398b85adc676da4238bc7",
+                "945373f0b3431a103333ba6a0a34f1efab2702efde41754c4cb1d5216d5b0a92a67458d968562bde7fa6310a83f53dda1383680a276a283438d58ceebfa7ab7ba72499d4a3eddc860595f63c93b1c5e823ea41fc490d938398a26db28f61857698553e93f0574eb8c5017bfed6249491f9976aaa8d23d9485339cc85ca329308",
+            ],
+        );
+    }
+}
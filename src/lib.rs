@@ -12,6 +12,8 @@ compile_error!("blstrs is only supported on little endian architectures");
 #[macro_use]
 mod macros;
 
+mod batch_invert;
+mod fixed_base;
 mod fp;
 mod fp12;
 mod fp2;
@@ -19,13 +21,23 @@ mod fp6;
 mod g1;
 mod g2;
 mod gt;
+mod hash_to_field;
+mod map_to_curve;
+mod msm;
 mod pairing;
 mod scalar;
 mod traits;
 
+pub use fixed_base::FixedBaseTable;
+pub use fp::{Fp, FpRepr};
+pub use fp12::Fp12;
+pub use fp2::Fp2;
+pub use fp6::Fp6;
 pub use g1::{G1Affine, G1Compressed, G1Projective, G1Uncompressed};
 pub use g2::{G2Affine, G2Compressed, G2Prepared, G2Projective, G2Uncompressed};
-pub use gt::Gt;
+pub use gt::{Gt, NotInSubgroupError};
+pub use hash_to_field::{ExpandMsg, ExpandMsgXmdSha256, ExpandMsgXofShake256};
+pub use msm::{G1MsmBases, G2MsmBases, MsmError};
 pub use pairing::*;
 pub use scalar::Scalar;
 pub use traits::Compress;
@@ -33,19 +45,23 @@ pub use traits::Compress;
 #[cfg(feature = "serde")]
 mod serde_impl;
 
+#[cfg(feature = "arkworks")]
+mod arkworks;
+#[cfg(feature = "arkworks")]
+pub use arkworks::{G1Config, G2Config};
+
+#[cfg(feature = "zkcrypto-interop")]
+mod zkcrypto;
+
 #[cfg(test)]
 mod tests;
 
-// export for benchmarking only
-#[cfg(feature = "__private_bench")]
-pub use crate::{fp::Fp, fp12::Fp12, fp2::Fp2};
-
 use ff::Field;
 use group::prime::PrimeCurveAffine;
 use pairing_lib::{Engine, MultiMillerLoop, PairingCurveAffine};
 
 /// Bls12-381 engine
-#[derive(Debug, Copy, Clone)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub struct Bls12;
 
 impl Engine for Bls12 {
@@ -68,7 +84,7 @@ impl MultiMillerLoop for Bls12 {
     /// Computes $$\sum_{i=1}^n \textbf{ML}(a_i, b_i)$$ given a series of terms
     /// $$(a_1, b_1), (a_2, b_2), ..., (a_n, b_n).$$
     fn multi_miller_loop(terms: &[(&Self::G1Affine, &Self::G2Prepared)]) -> Self::Result {
-        let mut res = blst::blst_fp12::default();
+        let mut res = crate::fp12::Fp12::one().0;
 
         for (i, (p, q)) in terms.iter().enumerate() {
             let mut tmp = blst::blst_fp12::default();
@@ -105,3 +121,9 @@ fn u64_to_u32(limbs: &[u64]) -> Vec<u32> {
 fn bls12_engine_tests() {
     crate::tests::engine::engine_tests::<Bls12>();
 }
+
+#[cfg(feature = "arkworks")]
+#[test]
+fn ark_bls12_engine_tests() {
+    crate::tests::ark_engine::engine_tests::<Bls12>();
+}
@@ -0,0 +1,373 @@
+//! Multi-scalar multiplication against a fixed set of bases.
+
+use core::{fmt, ops::Range, ptr};
+
+use blst::*;
+use group::{prime::PrimeCurveAffine, Curve, Group};
+
+use crate::{G1Affine, G1Projective, G2Affine, G2Projective, Scalar};
+
+/// The error returned by the checked multi-exponentiations, such as
+/// [`G1Projective::multi_exp_checked`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MsmError {
+    /// There are no points and no scalars.
+    Empty,
+    /// The number of points and the number of scalars differ.
+    LengthMismatch { points: usize, scalars: usize },
+}
+
+impl fmt::Display for MsmError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            MsmError::Empty => write!(f, "Empty multi-exponentiation"),
+            MsmError::LengthMismatch { points, scalars } => write!(
+                f,
+                "Length mismatch: {} points and {} scalars",
+                points, scalars
+            ),
+        }
+    }
+}
+
+impl std::error::Error for MsmError {}
+
+/// Checks that a multi-exponentiation has as many points as scalars, and at least one of each.
+pub(crate) fn check_lengths(points: usize, scalars: usize) -> Result<(), MsmError> {
+    if points != scalars {
+        Err(MsmError::LengthMismatch { points, scalars })
+    } else if points == 0 {
+        Err(MsmError::Empty)
+    } else {
+        Ok(())
+    }
+}
+
+/// The smallest number of points given to each thread by the parallel multi-exponentiations.
+/// Pippenger's algorithm gets less efficient per point on smaller inputs.
+#[cfg(feature = "parallel")]
+const MIN_PARALLEL_CHUNK: usize = 1 << 12;
+
+/// Splits a multi-exponentiation into one chunk per thread of the current `rayon` pool, computes
+/// each chunk with `multi_exp` and sums the results. Extra points or scalars are ignored.
+///
+/// `multi_exp` must run on the calling thread: `blst`'s own thread pool does not know about
+/// `rayon`'s, and starting it from every chunk would oversubscribe the cores.
+#[cfg(feature = "parallel")]
+pub(crate) fn par_multi_exp<P, G>(
+    points: &[P],
+    scalars: &[Scalar],
+    multi_exp: fn(&[P], &[Scalar]) -> G,
+) -> G
+where
+    P: Sync,
+    G: Group + Send,
+{
+    use rayon::prelude::*;
+
+    let n = points.len().min(scalars.len());
+    let threads = rayon::current_num_threads();
+    let chunk_size = ((n + threads - 1) / threads).max(MIN_PARALLEL_CHUNK);
+    if n <= chunk_size {
+        return multi_exp(&points[..n], &scalars[..n]);
+    }
+
+    points[..n]
+        .par_chunks(chunk_size)
+        .zip(scalars[..n].par_chunks(chunk_size))
+        .map(|(points, scalars)| multi_exp(points, scalars))
+        .reduce(G::identity, |a, b| a + b)
+}
+
+macro_rules! impl_msm_bases {
+    (
+        $bases:ident,
+        $affine:ident,
+        $projective:ident,
+        $blst_point:ident,
+        $blst_affine:ident,
+        $precompute_sizeof:ident,
+        $precompute:ident,
+        $scratch_sizeof:ident,
+        $mult_wbits:ident
+    ) => {
+        /// A set of bases for repeated multi-scalar multiplications.
+        ///
+        /// The bases are normalized once, and can optionally be precomputed into `blst`'s windowed
+        /// tables with [`precompute`](Self::precompute), which is faster than Pippenger's algorithm
+        /// for up to a few thousand points, at the expense of memory.
+        #[derive(Clone, Debug)]
+        pub struct $bases {
+            points: Vec<$affine>,
+            table: Option<PrecomputedTable<$blst_affine>>,
+        }
+
+        impl $bases {
+            /// Creates the bases from affine points.
+            pub fn new(points: &[$affine]) -> Self {
+                $bases {
+                    points: points.to_vec(),
+                    table: None,
+                }
+            }
+
+            /// Creates the bases from projective points, normalizing them.
+            pub fn from_projective(points: &[$projective]) -> Self {
+                let mut affine = vec![$affine::identity(); points.len()];
+                $projective::batch_normalize(points, &mut affine);
+                $bases {
+                    points: affine,
+                    table: None,
+                }
+            }
+
+            /// Precomputes the multiples of each base for windows of `wbits` bits.
+            ///
+            /// The table holds `2^(wbits - 1)` affine points per base.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `wbits` is not between 2 and 14.
+            pub fn precompute(&mut self, wbits: usize) {
+                assert!((2..=14).contains(&wbits), "wbits must be between 2 and 14");
+
+                // The table of the identity is not well defined, so it is replaced by the
+                // generator, and the matching scalars are zeroed in `msm_range`.
+                let mut identities = Vec::new();
+                let points: Vec<$blst_affine> = self
+                    .points
+                    .iter()
+                    .enumerate()
+                    .map(|(i, p)| {
+                        if bool::from(p.is_identity()) {
+                            identities.push(i);
+                            $affine::generator().0
+                        } else {
+                            p.0
+                        }
+                    })
+                    .collect();
+
+                let n = points.len();
+                let size = unsafe { $precompute_sizeof(wbits, n) };
+                let mut table =
+                    vec![$blst_affine::default(); size / core::mem::size_of::<$blst_affine>()];
+                if n > 0 {
+                    let p: [*const $blst_affine; 2] = [points.as_ptr(), ptr::null()];
+                    unsafe { $precompute(table.as_mut_ptr(), wbits, p.as_ptr(), n) };
+                }
+
+                self.table = Some(PrecomputedTable {
+                    wbits,
+                    table,
+                    identities,
+                });
+            }
+
+            /// Returns the number of bases.
+            pub fn len(&self) -> usize {
+                self.points.len()
+            }
+
+            /// Returns `true` if there are no bases.
+            pub fn is_empty(&self) -> bool {
+                self.points.is_empty()
+            }
+
+            /// Returns the bases.
+            pub fn points(&self) -> &[$affine] {
+                &self.points
+            }
+
+            /// Returns the window size of the precomputed table, if there is one.
+            pub fn wbits(&self) -> Option<usize> {
+                self.table.as_ref().map(|t| t.wbits)
+            }
+
+            /// Computes `sum(scalars[i] * bases[i])`. Extra bases or scalars are ignored.
+            pub fn msm(&self, scalars: &[Scalar]) -> $projective {
+                self.msm_range(0..self.len(), scalars)
+            }
+
+            /// Computes `sum(scalars[i] * bases[range.start + i])`. Extra bases in `range` or
+            /// extra scalars are ignored.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `range` is out of bounds.
+            pub fn msm_range(&self, range: Range<usize>, scalars: &[Scalar]) -> $projective {
+                let points = &self.points[range.clone()];
+                let table = match &self.table {
+                    Some(table) => table,
+                    None => return $affine::multi_exp(points, scalars),
+                };
+
+                let n = points.len().min(scalars.len());
+                if n == 0 {
+                    return $projective::identity();
+                }
+
+                let mut scalar_bytes: Vec<u8> = Vec::with_capacity(n * 32);
+                for a in scalars[..n].iter().map(|s| s.to_bytes_le()) {
+                    scalar_bytes.extend_from_slice(&a);
+                }
+                for i in &table.identities {
+                    if (range.start..range.start + n).contains(i) {
+                        let offset = (i - range.start) * 32;
+                        scalar_bytes[offset..offset + 32].fill(0);
+                    }
+                }
+
+                let rows = &table.table[range.start << (table.wbits - 1)..];
+                let s: [*const u8; 2] = [scalar_bytes.as_ptr(), ptr::null()];
+                let mut scratch = vec![0u64; unsafe { $scratch_sizeof(n) } / 8];
+                let mut out = $blst_point::default();
+                unsafe {
+                    $mult_wbits(
+                        &mut out,
+                        rows.as_ptr(),
+                        table.wbits,
+                        n,
+                        s.as_ptr(),
+                        255,
+                        scratch.as_mut_ptr(),
+                    )
+                };
+
+                $projective(out)
+            }
+        }
+    };
+}
+
+/// `blst`'s table of multiples for each base, see [`G1MsmBases::precompute`].
+#[derive(Clone, Debug)]
+struct PrecomputedTable<A> {
+    wbits: usize,
+    table: Vec<A>,
+    /// The indices of the identity bases.
+    identities: Vec<usize>,
+}
+
+impl_msm_bases!(
+    G1MsmBases,
+    G1Affine,
+    G1Projective,
+    blst_p1,
+    blst_p1_affine,
+    blst_p1s_mult_wbits_precompute_sizeof,
+    blst_p1s_mult_wbits_precompute,
+    blst_p1s_mult_wbits_scratch_sizeof,
+    blst_p1s_mult_wbits
+);
+
+impl_msm_bases!(
+    G2MsmBases,
+    G2Affine,
+    G2Projective,
+    blst_p2,
+    blst_p2_affine,
+    blst_p2s_mult_wbits_precompute_sizeof,
+    blst_p2s_mult_wbits_precompute,
+    blst_p2s_mult_wbits_scratch_sizeof,
+    blst_p2s_mult_wbits
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(feature = "parallel")]
+    #[test]
+    fn test_par_multi_exp() {
+        use ff::Field;
+        use rand_core::SeedableRng;
+        use rand_xorshift::XorShiftRng;
+
+        let mut rng = XorShiftRng::from_seed([
+            0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06,
+            0xbc, 0xe5,
+        ]);
+        let pool = rayon::ThreadPoolBuilder::new()
+            .num_threads(3)
+            .build()
+            .unwrap();
+
+        // Above `3 * MIN_PARALLEL_CHUNK` points, so that it is split in three chunks.
+        let size = 3 * MIN_PARALLEL_CHUNK + 5;
+        let mut p = G1Projective::random(&mut rng);
+        let points: Vec<G1Projective> = (0..size)
+            .map(|_| {
+                p += G1Projective::generator();
+                p
+            })
+            .collect();
+        let affine: Vec<G1Affine> = points.iter().map(|p| p.to_affine()).collect();
+        let scalars: Vec<Scalar> = (0..size).map(|_| Scalar::random(&mut rng)).collect();
+
+        for n in [0, 1, 10, MIN_PARALLEL_CHUNK + 1, size] {
+            let expected = G1Projective::multi_exp(&points[..n], &scalars[..n]);
+            pool.install(|| {
+                assert_eq!(
+                    G1Projective::multi_exp_parallel(&points, &scalars[..n]),
+                    expected,
+                    "n = {}",
+                    n
+                );
+                assert_eq!(
+                    G1Affine::multi_exp_parallel(&affine[..n], &scalars),
+                    expected,
+                    "n = {}",
+                    n
+                );
+            });
+        }
+
+        let points: Vec<G2Projective> = (0..MIN_PARALLEL_CHUNK + 3)
+            .map(|_| G2Projective::random(&mut rng))
+            .collect();
+        let affine: Vec<G2Affine> = points.iter().map(|p| p.to_affine()).collect();
+        let expected = G2Projective::multi_exp(&points, &scalars[..points.len()]);
+        pool.install(|| {
+            assert_eq!(
+                G2Projective::multi_exp_parallel(&points, &scalars),
+                expected
+            );
+            assert_eq!(G2Affine::multi_exp_parallel(&affine, &scalars), expected);
+        });
+    }
+
+    #[test]
+    fn test_check_lengths() {
+        assert_eq!(check_lengths(3, 3), Ok(()));
+        assert_eq!(check_lengths(0, 0), Err(MsmError::Empty));
+        assert_eq!(
+            check_lengths(0, 2),
+            Err(MsmError::LengthMismatch {
+                points: 0,
+                scalars: 2
+            })
+        );
+        assert_eq!(
+            check_lengths(2, 0),
+            Err(MsmError::LengthMismatch {
+                points: 2,
+                scalars: 0
+            })
+        );
+        assert_eq!(
+            check_lengths(5, 4),
+            Err(MsmError::LengthMismatch {
+                points: 5,
+                scalars: 4
+            })
+        );
+        assert_eq!(
+            check_lengths(4, 5),
+            Err(MsmError::LengthMismatch {
+                points: 4,
+                scalars: 5
+            })
+        );
+    }
+}
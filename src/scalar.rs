@@ -16,6 +16,8 @@ use ff::{Field, FieldBits, PrimeField, PrimeFieldBits};
 use rand_core::RngCore;
 use subtle::{Choice, ConditionallySelectable, ConstantTimeEq, CtOption};
 
+use crate::hash_to_field::{ExpandMsg, ExpandMsgXmdSha256};
+
 /// Represents an element of the scalar field $\mathbb{F}_q$ of the BLS12-381 elliptic
 /// curve construction.
 ///
@@ -25,7 +27,7 @@ use subtle::{Choice, ConditionallySelectable, ConstantTimeEq, CtOption};
 pub struct Scalar(pub(crate) blst_fr);
 
 // GENERATOR = 7 (multiplicative generator of r-1 order, that is also quadratic nonresidue)
-const GENERATOR: Scalar = Scalar(blst_fr {
+pub(crate) const GENERATOR: Scalar = Scalar(blst_fr {
     l: [
         0x0000_000e_ffff_fff1,
         0x17e3_63d3_0018_9c0f,
@@ -36,7 +38,7 @@ const GENERATOR: Scalar = Scalar(blst_fr {
 
 // Little-endian non-Montgomery form not reduced mod p.
 #[allow(dead_code)]
-const MODULUS: [u64; 4] = [
+pub(crate) const MODULUS: [u64; 4] = [
     0xffff_ffff_0000_0001,
     0x53bd_a402_fffe_5bfe,
     0x3339_d808_09a1_d805,
@@ -63,7 +65,7 @@ const MODULUS_REPR: [u8; 32] = [
 ];
 
 // `2^S` root of unity in little-endian Montgomery form.
-const ROOT_OF_UNITY: Scalar = Scalar(blst_fr {
+pub(crate) const ROOT_OF_UNITY: Scalar = Scalar(blst_fr {
     l: [
         0xb9b5_8d8c_5f0e_466a,
         0x5b1b_4c80_1819_d7ec,
@@ -72,14 +74,14 @@ const ROOT_OF_UNITY: Scalar = Scalar(blst_fr {
     ],
 });
 
-const ZERO: Scalar = Scalar(blst_fr { l: [0, 0, 0, 0] });
+pub(crate) const ZERO: Scalar = Scalar(blst_fr { l: [0, 0, 0, 0] });
 
 /// `R = 2^256 mod q` in little-endian Montgomery form which is equivalent to 1 in little-endian
 /// non-Montgomery form.
 ///
 /// sage> mod(2^256, 0x73eda753299d7d483339d80809a1d80553bda402fffe5bfeffffffff00000001)
 /// sage> 0x1824b159acc5056f998c4fefecbc4ff55884b7fa0003480200000001fffffffe
-const R: Scalar = Scalar(blst_fr {
+pub(crate) const R: Scalar = Scalar(blst_fr {
     l: [
         0x0000_0001_ffff_fffe,
         0x5884_b7fa_0003_4802,
@@ -104,6 +106,45 @@ const R2: Scalar = Scalar(blst_fr {
 
 pub const S: u32 = 32;
 
+/// `(q - 1) / 2` in little-endian non-Montgomery form.
+pub(crate) const MODULUS_MINUS_ONE_DIV_TWO: [u64; 4] = [
+    0x7fff_ffff_8000_0000,
+    0xa9de_d201_7fff_2dff,
+    0x199c_ec04_04d0_ec02,
+    0x39f6_d3a9_94ce_bea4,
+];
+
+/// `(t - 1) / 2` in little-endian non-Montgomery form, where `q - 1 = 2^S * t`.
+pub(crate) const TRACE_MINUS_ONE_DIV_TWO: [u64; 4] = [
+    0x7fff_2dff_7fff_ffff,
+    0x04d0_ec02_a9de_d201,
+    0x94ce_bea4_199c_ec04,
+    0x0000_0000_39f6_d3a9,
+];
+
+/// `|x|`, the absolute value of the BLS12-381 parameter `x = -0xd201000000010000`. The modulus is
+/// `q = x^4 - x^2 + 1`.
+pub(crate) const X_ABS: u64 = 0xd201_0000_0001_0000;
+
+/// The number of uniformly random bytes reduced to each scalar by `hash_to_field`,
+/// `L = ceil((ceil(log2(q)) + k) / 8)` for the security level `k = 128`.
+const HASH_TO_FIELD_LEN: usize = 48;
+
+/// Divides the little-endian integer `n` by [`X_ABS`], in constant time, returning the quotient
+/// and the remainder.
+fn div_rem_x_abs(n: &[u64; 4]) -> ([u64; 4], u64) {
+    let mut quotient = [0u64; 4];
+    let mut rem = 0u128;
+    for i in (0..256).rev() {
+        rem = (rem << 1) | ((n[i / 64] >> (i % 64)) & 1) as u128;
+        // `rem < 2 * X_ABS`, so the subtraction only wraps around if `rem < X_ABS`.
+        let ge = 1 - (rem.wrapping_sub(X_ABS as u128) >> 127) as u64;
+        rem -= X_ABS as u128 * ge as u128;
+        quotient[i / 64] |= ge << (i % 64);
+    }
+    (quotient, rem as u64)
+}
+
 impl fmt::Debug for Scalar {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         let be_bytes = self.to_bytes_be();
@@ -393,12 +434,7 @@ impl Field for Scalar {
 
         // w = self^((t - 1) // 2)
         //   = self^6104339283789297388802252303364915521546564123189034618274734669823
-        let w = self.pow_vartime(&[
-            0x7fff_2dff_7fff_ffff,
-            0x04d0_ec02_a9de_d201,
-            0x94ce_bea4_199c_ec04,
-            0x0000_0000_39f6_d3a9,
-        ]);
+        let w = self.pow_vartime(TRACE_MINUS_ONE_DIV_TWO);
 
         let mut v = S;
         let mut x = self * w;
@@ -550,6 +586,18 @@ impl PrimeFieldBits for Scalar {
 }
 
 impl Scalar {
+    /// Inverts all the elements of `v` in place with Montgomery's trick, which costs a single
+    /// inversion, in constant time. Zeros are left as zero.
+    pub fn batch_invert(v: &mut [Self]) {
+        crate::batch_invert::batch_invert(v)
+    }
+
+    /// Like [`Scalar::batch_invert`], but splits `v` across the threads of the current `rayon` pool.
+    #[cfg(feature = "parallel")]
+    pub fn batch_invert_parallel(v: &mut [Self]) {
+        crate::batch_invert::batch_invert_parallel(v)
+    }
+
     /// Attempts to convert a little-endian byte representation of
     /// a scalar into a `Scalar`, failing if the input is not canonical.
     pub fn from_bytes_le(bytes: &[u8; 32]) -> CtOption<Scalar> {
@@ -601,6 +649,57 @@ impl Scalar {
         CtOption::new(Scalar(out), is_some)
     }
 
+    /// Hashes `msg` to `count` scalars with the `hash_to_field` function of
+    /// [RFC 9380](https://www.rfc-editor.org/rfc/rfc9380.html#section-5.2), using
+    /// `expand_message_xmd` with SHA-256 and the domain separation tag `dst`.
+    ///
+    /// Panics if `count` is more than 170, the most `expand_message_xmd` can produce.
+    pub fn hash_to_field(msg: &[u8], dst: &[u8], count: usize) -> Vec<Scalar> {
+        Scalar::hash_to_field_with::<ExpandMsgXmdSha256>(msg, dst, count)
+    }
+
+    /// Like [`Scalar::hash_to_field`], but with the `expand_message` function `X`, such as
+    /// [`ExpandMsgXofShake256`](crate::ExpandMsgXofShake256).
+    ///
+    /// Panics if `count` is more than `X::MAX_LEN / 48`, 1365 for `ExpandMsgXofShake256`.
+    pub fn hash_to_field_with<X: ExpandMsg>(msg: &[u8], dst: &[u8], count: usize) -> Vec<Scalar> {
+        assert!(
+            count <= X::MAX_LEN / HASH_TO_FIELD_LEN,
+            "hash_to_field can produce at most {} scalars",
+            X::MAX_LEN / HASH_TO_FIELD_LEN
+        );
+        Scalar::from_uniform_bytes(&X::expand_message(msg, dst, count * HASH_TO_FIELD_LEN))
+    }
+
+    /// Reduces each `HASH_TO_FIELD_LEN` big-endian bytes of `bytes` modulo `q`.
+    fn from_uniform_bytes(bytes: &[u8]) -> Vec<Scalar> {
+        bytes
+            .chunks(HASH_TO_FIELD_LEN)
+            .map(|chunk| {
+                let mut raw = blst_scalar::default();
+                let mut out = blst_fr::default();
+                unsafe {
+                    blst_scalar_from_be_bytes(&mut raw, chunk.as_ptr(), chunk.len());
+                    blst_fr_from_scalar(&mut out, &raw);
+                }
+                Scalar(out)
+            })
+            .collect()
+    }
+
+    /// Returns the digits `[d0, d1, d2, d3]` of `self` in base `|x|`, so that
+    /// `self = d0 + d1 |x| + d2 |x|^2 + d3 |x|^3`. Computed in constant time.
+    pub(crate) fn to_x_abs_digits(&self) -> [u64; 4] {
+        let mut n = [0u64; 4];
+        unsafe { blst_uint64_from_fr(n.as_mut_ptr(), &self.0) };
+
+        let (n, d0) = div_rem_x_abs(&n);
+        let (n, d1) = div_rem_x_abs(&n);
+        let (n, d2) = div_rem_x_abs(&n);
+        // `self < q < |x|^4`, so the last quotient is a single digit.
+        [d0, d1, d2, n[0]]
+    }
+
     #[allow(clippy::match_like_matches_macro)]
     pub fn is_quad_res(&self) -> Choice {
         match self.legendre() {
@@ -610,14 +709,8 @@ impl Scalar {
     }
 
     pub fn legendre(&self) -> i8 {
-        const MOD_MINUS_1_OVER_2: [u64; 4] = [
-            0x7fffffff80000000,
-            0xa9ded2017fff2dff,
-            0x199cec0404d0ec02,
-            0x39f6d3a994cebea4,
-        ];
         // s = self^((modulus - 1) // 2)
-        let s = self.pow_vartime(MOD_MINUS_1_OVER_2);
+        let s = self.pow_vartime(MODULUS_MINUS_ONE_DIV_TWO);
         if s == Self::zero() {
             0
         } else if s == Self::one() {
@@ -1029,7 +1122,7 @@ mod tests {
 
         for _ in 0..100 {
             r1 = r1.invert().unwrap();
-            r2 = r2.pow_vartime(&q_minus_2);
+            r2 = r2.pow_vartime(q_minus_2);
 
             assert_eq!(r1, r2);
             // Add R so we check something different next time around
@@ -1121,7 +1214,7 @@ mod tests {
 
     #[test]
     fn test_scalar_from_u64() {
-        let a = Scalar::from(100);
+        let a = Scalar::from(100u64);
         let mut expected_bytes = [0u8; 32];
         expected_bytes[0] = 100;
         assert_eq!(a.to_bytes_le(), expected_bytes);
@@ -1129,16 +1222,16 @@ mod tests {
 
     #[test]
     fn test_scalar_is_odd() {
-        assert!(bool::from(Scalar::from(0).is_even()));
-        assert!(bool::from(Scalar::from(1).is_odd()));
-        assert!(bool::from(Scalar::from(324834872).is_even()));
-        assert!(bool::from(Scalar::from(324834873).is_odd()));
+        assert!(bool::from(Scalar::from(0u64).is_even()));
+        assert!(bool::from(Scalar::from(1u64).is_odd()));
+        assert!(bool::from(Scalar::from(324834872u64).is_even()));
+        assert!(bool::from(Scalar::from(324834873u64).is_odd()));
     }
 
     #[test]
     fn test_scalar_is_zero() {
-        assert!(bool::from(Scalar::from(0).is_zero()));
-        assert!(!bool::from(Scalar::from(1).is_zero()));
+        assert!(bool::from(Scalar::from(0u64).is_zero()));
+        assert!(!bool::from(Scalar::from(1u64).is_zero()));
         assert!(!bool::from(
             Scalar::from_u64s_le(&[0, 0, 1, 0]).unwrap().is_zero()
         ));
@@ -1149,9 +1242,9 @@ mod tests {
         assert_eq!(Scalar::NUM_BITS, 255);
         assert_eq!(Scalar::CAPACITY, 254);
 
-        let mut a = Scalar::from(0);
+        let mut a = Scalar::from(0u64);
         assert_eq!(0, a.num_bits());
-        a = Scalar::from(1);
+        a = Scalar::from(1u64);
         assert_eq!(1, a.num_bits());
         for i in 2..Scalar::NUM_BITS {
             a = a.shl(1);
@@ -1604,7 +1697,7 @@ mod tests {
             // Exponentiate by various small numbers and ensure it consists with repeated
             // multiplication.
             let a = Scalar::random(&mut rng);
-            let target = a.pow_vartime(&[i]);
+            let target = a.pow_vartime([i]);
             let mut c = Scalar::one();
             for _ in 0..i {
                 c.mul_assign(&a);
@@ -1616,7 +1709,7 @@ mod tests {
             // Exponentiating by the modulus should have no effect in a prime field.
             let a = Scalar::random(&mut rng);
 
-            assert_eq!(a, a.pow_vartime(&MODULUS));
+            assert_eq!(a, a.pow_vartime(MODULUS));
         }
     }
 
@@ -1745,7 +1838,7 @@ mod tests {
     #[test]
     fn test_scalar_root_of_unity() {
         assert_eq!(Scalar::S, 32);
-        assert_eq!(Scalar::multiplicative_generator(), Scalar::from(7));
+        assert_eq!(Scalar::multiplicative_generator(), Scalar::from(7u64));
         assert_eq!(
             Scalar::multiplicative_generator().pow_vartime([
                 0xfffe5bfeffffffff,
@@ -1773,14 +1866,14 @@ mod tests {
 
     #[test]
     fn test_scalar_repr_conversion() {
-        let a = Scalar::from(1);
+        let a = Scalar::from(1u64);
         let mut expected_bytes = [0u8; 32];
         expected_bytes[0] = 1;
         assert_eq!(a, Scalar::from_repr(a.to_repr()).unwrap());
         assert_eq!(a.to_repr(), expected_bytes);
         assert_eq!(a, Scalar::from_repr(expected_bytes).unwrap());
 
-        let a = Scalar::from(12);
+        let a = Scalar::from(12u64);
         let mut expected_bytes = [0u8; 32];
         expected_bytes[0] = 12;
         assert_eq!(a, Scalar::from_repr(a.to_repr()).unwrap());
@@ -1790,14 +1883,14 @@ mod tests {
 
     #[test]
     fn test_scalar_repr_vartime_conversion() {
-        let a = Scalar::from(1);
+        let a = Scalar::from(1u64);
         let mut expected_bytes = [0u8; 32];
         expected_bytes[0] = 1;
         assert_eq!(a, Scalar::from_repr_vartime(a.to_repr()).unwrap());
         assert_eq!(a.to_repr(), expected_bytes);
         assert_eq!(a, Scalar::from_repr_vartime(expected_bytes).unwrap());
 
-        let a = Scalar::from(12);
+        let a = Scalar::from(12u64);
         let mut expected_bytes = [0u8; 32];
         expected_bytes[0] = 12;
         assert_eq!(a, Scalar::from_repr_vartime(a.to_repr()).unwrap());
@@ -1838,7 +1931,7 @@ mod tests {
     #[test]
     fn m1_inv_bug() {
         // This fails on aarch64-darwin.
-        let bad = Scalar::zero() - Scalar::from(7);
+        let bad = Scalar::zero() - Scalar::from(7u64);
 
         let inv = bad.invert().unwrap();
         let check = inv * bad;
@@ -1847,7 +1940,7 @@ mod tests {
     #[test]
     fn m1_inv_bug_more() {
         let mut bad = Vec::new();
-        for i in 1..1000000 {
+        for i in 1..1000000u64 {
             // Ensure that a * a^-1 = 1
             let a = Scalar::zero() - Scalar::from(i);
             let ainv = a.invert().unwrap();
@@ -1894,4 +1987,131 @@ mod tests {
         }
         assert_eq!(0, yep_bad.len());
     }
+
+    #[test]
+    fn test_to_x_abs_digits() {
+        let mut rng = XorShiftRng::from_seed([
+            0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06,
+            0xbc, 0xe5,
+        ]);
+
+        let x_abs = Scalar::from(X_ABS);
+        let scalars = (0..100).map(|_| Scalar::random(&mut rng)).chain([
+            Scalar::zero(),
+            Scalar::one(),
+            -Scalar::one(),
+            x_abs,
+        ]);
+        for s in scalars {
+            let digits = s.to_x_abs_digits();
+            assert!(digits.iter().all(|d| *d < X_ABS));
+            let recomposed = digits
+                .iter()
+                .rev()
+                .fold(Scalar::zero(), |acc, d| acc * x_abs + Scalar::from(*d));
+            assert_eq!(recomposed, s);
+        }
+        assert_eq!(x_abs.to_x_abs_digits(), [0, 1, 0, 0]);
+    }
+
+    // Each scalar is the big-endian integer of 48 uniform bytes, reduced modulo `q`.
+    fn reduce_uniform_bytes(bytes: &[u8]) -> Scalar {
+        let two_128 = (Scalar::from(u64::MAX) + Scalar::one()).square();
+        bytes.chunks(16).fold(Scalar::zero(), |acc, chunk| {
+            let mut be = [0u8; 32];
+            be[16..].copy_from_slice(chunk);
+            acc * two_128 + Scalar::from_bytes_be(&be).unwrap()
+        })
+    }
+
+    // These are not RFC 9380 test vectors, as the RFC has none for the scalar field, and the
+    // `QUUX` domain separation tags only borrow its naming. They were generated with
+    // `bls12_381_plus` 0.8.18, reducing each 48-byte chunk of the `ExpandMsgXmd<Sha256>` output of
+    // `elliptic-curve` 0.13.8 with `Scalar::from_okm`.
+    #[test]
+    fn test_hash_to_field() {
+        use crate::hash_to_field::expand_message_xmd;
+
+        let dst = b"QUUX-V01-CS02-with-BLS12381SCALAR_XMD:SHA-256_";
+        let expected = [
+            (
+                &b""[..],
+                [
+                    "69c3d4fc115fc418197c241fdb670cdb9fc677688ae60bac2d683bbedd79cc02",
+                    "624ba1124f995406a65567c09041af6a51ff7de6d330a15e35e9aa45e687e17d",
+                ],
+            ),
+            (
+                &b"abc"[..],
+                [
+                    "48fd6b7b78ad41a9d6c6f5cee7d8892998c49bdcfa94f5b4204abc8729f95bd3",
+                    "6abaf7163a9e76d17880aeec3b41cc0cd19e5ca99dca17510d164f7b051b8dfb",
+                ],
+            ),
+        ];
+        for (msg, expected) in expected.iter() {
+            let scalars = Scalar::hash_to_field(msg, dst, 2);
+            assert_eq!(scalars.len(), 2);
+            for (s, e) in scalars.iter().zip(expected.iter()) {
+                assert_eq!(hex::encode(s.to_bytes_be()), *e);
+            }
+
+            let uniform_bytes = expand_message_xmd(msg, dst, 2 * HASH_TO_FIELD_LEN);
+            for (s, chunk) in scalars.iter().zip(uniform_bytes.chunks(HASH_TO_FIELD_LEN)) {
+                assert_eq!(*s, reduce_uniform_bytes(chunk));
+            }
+        }
+
+        assert!(Scalar::hash_to_field(b"abc", dst, 0).is_empty());
+        assert_eq!(Scalar::hash_to_field(b"abc", dst, 170).len(), 170);
+    }
+
+    // Not RFC 9380 test vectors either: generated with `bls12_381_plus` 0.8.18 as above, with the
+    // `ExpandMsgXof<Shake256>` expander of `elliptic-curve` 0.13.8.
+    #[test]
+    fn test_hash_to_field_with_xof() {
+        use crate::hash_to_field::{expand_message_xof, ExpandMsgXofShake256};
+
+        let dst = b"QUUX-V01-CS02-with-BLS12381SCALAR_XOF:SHAKE256_";
+        let expected = [
+            (
+                &b""[..],
+                [
+                    "1c559a67d6ce3ac3b7bd07498a2e590cf4e1f67f2972f68c5e435fd40f70c3bf",
+                    "300b956d4be74ab95d08427df504e040af673a05fe043675e95e76e35b8951cf",
+                ],
+            ),
+            (
+                &b"abc"[..],
+                [
+                    "71d463cd5c41eec1908b32a7171cbc4e80d3ec6021fded797158691b7da0477b",
+                    "322af564d00361345f161cedc7705bc07c4f6b24336dd72c1e2df22b243f2595",
+                ],
+            ),
+        ];
+        for (msg, expected) in expected.iter() {
+            let scalars = Scalar::hash_to_field_with::<ExpandMsgXofShake256>(msg, dst, 2);
+            assert_eq!(scalars.len(), 2);
+            for (s, e) in scalars.iter().zip(expected.iter()) {
+                assert_eq!(hex::encode(s.to_bytes_be()), *e);
+            }
+
+            let uniform_bytes = expand_message_xof(msg, dst, 2 * HASH_TO_FIELD_LEN);
+            for (s, chunk) in scalars.iter().zip(uniform_bytes.chunks(HASH_TO_FIELD_LEN)) {
+                assert_eq!(*s, reduce_uniform_bytes(chunk));
+            }
+        }
+
+        assert!(Scalar::hash_to_field_with::<ExpandMsgXofShake256>(b"abc", dst, 0).is_empty());
+        assert_eq!(
+            Scalar::hash_to_field_with::<ExpandMsgXofShake256>(b"abc", dst, 1365).len(),
+            1365
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_hash_to_field_too_many() {
+        Scalar::hash_to_field(b"abc", b"dst", 171);
+    }
 }
@@ -163,7 +163,7 @@ impl Serialize for MillerLoopResult {
 impl<'de> Deserialize<'de> for Gt {
     fn deserialize<D: Deserializer<'de>>(d: D) -> Result<Self, D::Error> {
         let fp12 = Fp12::deserialize(d)?;
-        Ok(Gt(fp12))
+        Gt::try_from(fp12).map_err(DeserializeError::custom)
     }
 }
 
@@ -430,4 +430,21 @@ mod tests {
             f
         );
     }
+
+    #[test]
+    fn serde_gt() {
+        let mut rng = XorShiftRng::from_seed([
+            0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06,
+            0xbc, 0xe5,
+        ]);
+        for _ in 0..10 {
+            let g = Gt::random(&mut rng);
+            test_roundtrip(&g);
+
+            let f = Fp12::random(&mut rng);
+            let ser = serde_json::to_vec(&f).unwrap();
+            assert!(serde_json::from_slice::<Gt>(&ser).is_err());
+        }
+        test_roundtrip(&Gt::identity());
+    }
 }
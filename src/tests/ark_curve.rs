@@ -0,0 +1,75 @@
+//! The checks of the `curve` arm of `ark_algebra_test_templates::test_group!`, which only builds
+//! for the arkworks short Weierstrass types: it compares affine and projective points directly
+//! and expects `is_on_curve` to return a `bool`.
+
+use ark_ec::{AffineRepr, CurveConfig, CurveGroup};
+use ark_ff::{PrimeField, UniformRand, Zero};
+use ark_serialize::CanonicalSerialize;
+use ark_std::{rand::RngCore, test_rng};
+
+const ITERATIONS: usize = 500;
+
+pub fn curve_tests<G: CurveGroup>() {
+    let mut rng = test_rng();
+    let modulus = <G::ScalarField as PrimeField>::MODULUS;
+
+    for _ in 0..ITERATIONS {
+        // Affine conversion.
+        let g = G::rand(&mut rng);
+        assert_eq!(g.into_affine().into_group(), g);
+
+        // Mixed addition.
+        let a = G::Affine::rand(&mut rng);
+        let b = G::rand(&mut rng);
+        assert_eq!(b + a, b + a.into_group());
+        assert_eq!(a + b, a.into_group() + b);
+
+        // Cofactor operations.
+        assert_eq!(
+            a.mul_by_cofactor_to_group(),
+            a.mul_bigint(G::Config::COFACTOR)
+        );
+        assert_eq!(
+            a.mul_by_cofactor().into_group(),
+            a.mul_bigint(G::Config::COFACTOR)
+        );
+        assert_eq!(a.mul_by_cofactor().mul_by_cofactor_inv(), a);
+        assert_eq!(a.mul_by_cofactor_inv().mul_by_cofactor(), a);
+        assert_eq!(
+            a.mul_by_cofactor_inv().into_group(),
+            a * G::Config::COFACTOR_INV
+        );
+        assert!(a.clear_cofactor().mul_bigint(modulus).is_zero());
+    }
+
+    // Batch normalization.
+    for _ in 0..10 {
+        let mut v: Vec<G> = (0..ITERATIONS)
+            .map(|_| G::rand(&mut rng).double())
+            .collect();
+        for i in (0..ITERATIONS).step_by(100) {
+            v[i] = G::zero();
+            v[i + 1] = v[i + 1].into_affine().into_group();
+        }
+
+        let expected: Vec<_> = v.iter().map(|v| v.into_affine()).collect();
+        assert_eq!(G::normalize_batch(&v), expected);
+    }
+
+    // Points outside of the prime order subgroup.
+    let mut found = 0;
+    while found < 10 {
+        let mut bytes = vec![0u8; G::BaseField::zero().compressed_size()];
+        rng.fill_bytes(&mut bytes);
+        let p = match G::Affine::from_random_bytes(&bytes) {
+            Some(p) => p,
+            None => continue,
+        };
+        found += 1;
+
+        assert!(!p.mul_bigint(modulus).is_zero());
+        let cleared = p.clear_cofactor();
+        assert!(!cleared.is_zero());
+        assert!(cleared.mul_bigint(modulus).is_zero());
+    }
+}
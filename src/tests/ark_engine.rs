@@ -0,0 +1,152 @@
+//! The arkworks counterpart of the engine tests in `engine.rs`.
+
+use ark_ec::{
+    pairing::{Pairing, PairingOutput},
+    AffineRepr, CurveGroup, Group,
+};
+use ark_ff::{CyclotomicMultSubgroup, Field, One, UniformRand, Zero};
+use ark_std::test_rng;
+
+const ITERATIONS: usize = 100;
+
+pub fn engine_tests<E: Pairing>() {
+    let mut rng = test_rng();
+
+    for _ in 0..10 {
+        let a = E::G1::rand(&mut rng).into_affine();
+        let b = E::G2::rand(&mut rng).into_affine();
+
+        assert_eq!(E::pairing(a, b), E::multi_pairing([a], [b]));
+        assert_eq!(
+            E::pairing(a, b),
+            E::final_exponentiation(E::miller_loop(a, b)).unwrap()
+        );
+    }
+
+    for _ in 0..ITERATIONS {
+        let z1 = E::G1Affine::zero();
+        let z2 = E::G2Prepared::from(E::G2Affine::zero());
+
+        let a = E::G1::rand(&mut rng).into_affine();
+        let b = E::G2Prepared::from(E::G2::rand(&mut rng).into_affine());
+        let c = E::G1::rand(&mut rng).into_affine();
+        let d = E::G2Prepared::from(E::G2::rand(&mut rng).into_affine());
+
+        assert!(E::multi_pairing([z1], [b.clone()]).is_zero());
+        assert!(E::multi_pairing([a], [z2.clone()]).is_zero());
+
+        assert_eq!(
+            E::multi_pairing([z1, c], [b.clone(), d.clone()]),
+            E::multi_pairing([a, c], [z2.clone(), d.clone()]),
+        );
+
+        assert_eq!(
+            E::multi_pairing([a, z1], [b.clone(), d.clone()]),
+            E::multi_pairing([a, c], [b, z2]),
+        );
+    }
+
+    assert!(E::multi_pairing(Vec::<E::G1Affine>::new(), Vec::<E::G2Prepared>::new()).is_zero());
+
+    random_miller_loop_tests::<E>();
+    random_bilinearity_tests::<E>();
+    pairing_output_tests::<E>();
+}
+
+fn random_miller_loop_tests<E: Pairing>() {
+    let mut rng = test_rng();
+
+    // Exercise the miller loop for a reduced pairing
+    for _ in 0..ITERATIONS {
+        let a = E::G1::rand(&mut rng);
+        let b = E::G2::rand(&mut rng);
+
+        let p2 = E::pairing(a, b);
+
+        let b = E::G2Prepared::from(b);
+        let p1 = E::final_exponentiation(E::miller_loop(a, b)).unwrap();
+
+        assert_eq!(p1, p2);
+    }
+
+    // Exercise a double miller loop
+    for _ in 0..ITERATIONS {
+        let a = E::G1::rand(&mut rng).into_affine();
+        let b = E::G2::rand(&mut rng).into_affine();
+        let c = E::G1::rand(&mut rng).into_affine();
+        let d = E::G2::rand(&mut rng).into_affine();
+
+        let ab = E::pairing(a, b);
+        let cd = E::pairing(c, d);
+
+        let abcd = ab + cd;
+
+        let b = E::G2Prepared::from(b);
+        let d = E::G2Prepared::from(d);
+
+        let abcd_with_double_loop =
+            E::final_exponentiation(E::multi_miller_loop([a, c], [b, d])).unwrap();
+
+        assert_eq!(abcd, abcd_with_double_loop);
+    }
+}
+
+fn random_bilinearity_tests<E: Pairing>() {
+    let mut rng = test_rng();
+
+    for _ in 0..ITERATIONS {
+        let a = E::G1::rand(&mut rng);
+        let b = E::G2::rand(&mut rng);
+
+        let c = E::ScalarField::rand(&mut rng);
+        let d = E::ScalarField::rand(&mut rng);
+
+        let ac = a * c;
+        let ad = a * d;
+        let bc = b * c;
+        let bd = b * d;
+
+        // Check that `e([c]a, [d]b) == e([d]a, [c]b)`.
+        let acbd = E::pairing(ac, bd);
+        let adbc = E::pairing(ad, bc);
+        assert_eq!(acbd, adbc);
+
+        let cd = c * d;
+        let acd = ac * d;
+        let bcd = bc * d;
+
+        // Check that `[d][c]a == [cd]a`.
+        assert_eq!(acd, a * cd);
+        assert_eq!(bcd, b * cd);
+
+        // Check that `e([c]a, [d]b) == e([cd]a, b) == e(a, [cd]b) == [cd]e(a, b)`.
+        assert_eq!(acbd, E::pairing(acd, b));
+        assert_eq!(acbd, E::pairing(a, bcd));
+        assert_eq!(acbd, E::pairing(a, b) * cd);
+    }
+}
+
+fn pairing_output_tests<E: Pairing>() {
+    let mut rng = test_rng();
+    let generator = PairingOutput::<E>::generator();
+
+    assert!(!generator.is_zero());
+    assert_eq!(
+        generator,
+        E::pairing(E::G1Affine::generator(), E::G2Affine::generator())
+    );
+    assert!((generator * -E::ScalarField::one() + generator).is_zero());
+
+    for _ in 0..10 {
+        let a = PairingOutput::<E>::rand(&mut rng);
+        let b = PairingOutput::<E>::rand(&mut rng);
+        let s = E::ScalarField::rand(&mut rng);
+
+        assert_eq!(a - b + b, a);
+        assert_eq!(a + (-a), PairingOutput::<E>::zero());
+        assert_eq!(a.double(), a + a);
+        assert_eq!((a + b) * s, a * s + b * s);
+        assert_eq!(a.0.cyclotomic_square(), a.0.square());
+        assert_eq!(a.0.cyclotomic_inverse(), a.0.inverse());
+    }
+}
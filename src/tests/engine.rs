@@ -50,6 +50,11 @@ pub fn engine_tests<E: Engine + MultiMillerLoop>() {
         );
     }
 
+    assert_eq!(
+        E::Gt::identity(),
+        E::multi_miller_loop(&[]).final_exponentiation()
+    );
+
     random_miller_loop_tests::<E>();
     random_bilinearity_tests::<E>();
 }
@@ -1,3 +1,7 @@
+#[cfg(feature = "arkworks")]
+pub mod ark_curve;
+#[cfg(feature = "arkworks")]
+pub mod ark_engine;
 pub mod engine;
 pub mod field;
 
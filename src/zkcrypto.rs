@@ -0,0 +1,475 @@
+//! Conversions to and from the types of the zkcrypto `bls12_381` crate.
+//!
+//! Both crates store field elements as Montgomery limbs with the same radix, so scalars and the
+//! coordinates of points are copied as they are, without reparsing. Affine points differ only in
+//! how they mark the identity, and projective points are converted between the Jacobian
+//! coordinates of `blst` and the homogeneous coordinates of `bls12_381` with a few
+//! multiplications.
+//!
+//! `bls12_381` keeps the representation of its types private, with Rust's default layout, so
+//! [`same_layout`] checks it once against known values before any limbs are copied. If it ever
+//! fails, the conversions fall back to the public encodings of the two crates.
+//!
+//! There is no conversion for `Gt`, since `bls12_381::Gt` has neither an encoding nor accessors
+//! for its coefficients.
+
+use core::{
+    mem::{align_of, size_of, MaybeUninit},
+    ptr,
+};
+
+use blst::*;
+use ff::Field;
+use group::{prime::PrimeCurveAffine, Curve, Group};
+use once_cell::sync::OnceCell;
+
+use crate::{fp::Fp, fp2::Fp2, G1Affine, G1Projective, G2Affine, G2Projective, Scalar};
+
+/// Reads the first `N` words of `value`.
+///
+/// # Safety
+///
+/// The first `8 N` bytes of `value` must be initialized, which is the case for the `blst` types
+/// and, once [`same_layout`] holds, for the leading limbs of the `bls12_381` types.
+unsafe fn to_words<T, const N: usize>(value: &T) -> [u64; N] {
+    assert!(size_of::<T>() >= size_of::<[u64; N]>() && align_of::<T>() >= align_of::<u64>());
+    ptr::read(value as *const T as *const [u64; N])
+}
+
+/// Builds a `T` that starts with `words`, with its remaining bytes zeroed.
+///
+/// # Safety
+///
+/// Any bytes must be a valid `T`. This holds for the `blst` and `bls12_381` types, whose fields
+/// are all integers.
+unsafe fn from_words<T, const N: usize>(words: [u64; N]) -> T {
+    assert!(size_of::<T>() >= size_of::<[u64; N]>() && align_of::<T>() >= align_of::<u64>());
+    let mut out = MaybeUninit::<T>::zeroed();
+    ptr::write(out.as_mut_ptr() as *mut [u64; N], words);
+    out.assume_init()
+}
+
+/// Whether the `bls12_381` types start with the same Montgomery limbs as their `blst`
+/// counterparts: the scalar limbs, or the coordinates `x`, `y` and, for projective points, `z`.
+///
+/// Writing limbs into the `bls12_381` types is always sound, as any bytes are a valid value, so
+/// this builds values from limbs and compares them with the expected ones. A different field
+/// order, or a different Montgomery form, makes the comparison fail. Only then is it sound to
+/// read limbs back out.
+fn same_layout() -> bool {
+    static SAME_LAYOUT: OnceCell<bool> = OnceCell::new();
+    *SAME_LAYOUT.get_or_init(|| {
+        let scalar = -Scalar::from(2u64);
+        let (g1, g2) = (G1Affine::generator(), G2Affine::generator());
+        let (two, two2) = (Fp::from(2u64), Fp2::from(Fp::from(2u64)));
+        // The generators in homogeneous coordinates, scaled by 2 so that `z` is not one.
+        let g1_projective = blst_p1 {
+            x: (g1.x() * two).0,
+            y: (g1.y() * two).0,
+            z: two.0,
+        };
+        let g2_projective = blst_p2 {
+            x: (g2.x() * two2).0,
+            y: (g2.y() * two2).0,
+            z: two2.0,
+        };
+
+        unsafe {
+            from_words::<bls12_381::Scalar, 4>(scalar.0.l) == -bls12_381::Scalar::from(2)
+                && from_words::<bls12_381::G1Affine, 12>(to_words(&g1.0))
+                    == bls12_381::G1Affine::generator()
+                && from_words::<bls12_381::G1Projective, 18>(to_words(&g1_projective))
+                    == bls12_381::G1Projective::generator()
+                && from_words::<bls12_381::G2Affine, 24>(to_words(&g2.0))
+                    == bls12_381::G2Affine::generator()
+                && from_words::<bls12_381::G2Projective, 36>(to_words(&g2_projective))
+                    == bls12_381::G2Projective::generator()
+        }
+    })
+}
+
+impl From<Scalar> for bls12_381::Scalar {
+    fn from(s: Scalar) -> bls12_381::Scalar {
+        if same_layout() {
+            unsafe { from_words(s.0.l) }
+        } else {
+            bls12_381::Scalar::from_bytes(&s.to_bytes_le()).unwrap()
+        }
+    }
+}
+
+impl From<bls12_381::Scalar> for Scalar {
+    fn from(s: bls12_381::Scalar) -> Scalar {
+        if same_layout() {
+            Scalar(blst_fr {
+                l: unsafe { to_words(&s) },
+            })
+        } else {
+            Scalar::from_bytes_le(&s.to_bytes()).unwrap()
+        }
+    }
+}
+
+macro_rules! impl_zkcrypto_curve {
+    (
+        $affine:ident,
+        $projective:ident,
+        $field:ident,
+        $blst_affine:ident,
+        $blst_projective:ident,
+        affine_words: $affine_words:literal,
+        projective_words: $projective_words:literal $(,)?
+    ) => {
+        impl From<$affine> for bls12_381::$affine {
+            fn from(p: $affine) -> bls12_381::$affine {
+                if bool::from(p.is_identity()) {
+                    bls12_381::$affine::identity()
+                } else if same_layout() {
+                    unsafe { from_words(to_words::<$blst_affine, $affine_words>(&p.0)) }
+                } else {
+                    bls12_381::$affine::from_uncompressed_unchecked(&p.to_uncompressed()).unwrap()
+                }
+            }
+        }
+
+        impl From<bls12_381::$affine> for $affine {
+            fn from(p: bls12_381::$affine) -> $affine {
+                if bool::from(p.is_identity()) {
+                    $affine::identity()
+                } else if same_layout() {
+                    $affine(unsafe { from_words(to_words::<_, $affine_words>(&p)) })
+                } else {
+                    $affine::from_uncompressed_unchecked(&p.to_uncompressed()).unwrap()
+                }
+            }
+        }
+
+        impl From<$projective> for bls12_381::$projective {
+            fn from(p: $projective) -> bls12_381::$projective {
+                if bool::from(p.is_identity()) {
+                    bls12_381::$projective::identity()
+                } else if same_layout() {
+                    // Jacobian `(X, Y, Z)` to homogeneous `(X Z, Y, Z^3)`.
+                    let (x, y, z) = ($field(p.0.x), $field(p.0.y), $field(p.0.z));
+                    let h = $blst_projective {
+                        x: (x * z).0,
+                        y: y.0,
+                        z: (z.square() * z).0,
+                    };
+                    unsafe { from_words(to_words::<_, $projective_words>(&h)) }
+                } else {
+                    bls12_381::$affine::from(p.to_affine()).into()
+                }
+            }
+        }
+
+        impl From<bls12_381::$projective> for $projective {
+            fn from(p: bls12_381::$projective) -> $projective {
+                if bool::from(p.is_identity()) {
+                    $projective::identity()
+                } else if same_layout() {
+                    // Homogeneous `(X, Y, Z)` to Jacobian `(X Z, Y Z^2, Z)`.
+                    let h: $blst_projective =
+                        unsafe { from_words(to_words::<_, $projective_words>(&p)) };
+                    let (x, y, z) = ($field(h.x), $field(h.y), $field(h.z));
+                    $projective($blst_projective {
+                        x: (x * z).0,
+                        y: (y * z.square()).0,
+                        z: z.0,
+                    })
+                } else {
+                    $affine::from(bls12_381::$affine::from(p)).into()
+                }
+            }
+        }
+    };
+}
+
+impl_zkcrypto_curve!(
+    G1Affine,
+    G1Projective,
+    Fp,
+    blst_p1_affine,
+    blst_p1,
+    affine_words: 12,
+    projective_words: 18,
+);
+impl_zkcrypto_curve!(
+    G2Affine,
+    G2Projective,
+    Fp2,
+    blst_p2_affine,
+    blst_p2,
+    affine_words: 24,
+    projective_words: 36,
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::convert::{TryFrom, TryInto};
+
+    use rand_core::SeedableRng;
+    use rand_xorshift::XorShiftRng;
+
+    use crate::{fp12::Fp12, Bls12, G2Prepared, Gt};
+    use pairing_lib::{MillerLoopResult, MultiMillerLoop};
+
+    fn test_rng() -> XorShiftRng {
+        XorShiftRng::from_seed([
+            0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06,
+            0xbc, 0xe5,
+        ])
+    }
+
+    // `bls12_381::Gt` has no encoding, so the pairing tests read it from its `Debug` output,
+    // which prints the coefficients in storage order as `0x` followed by 96 hex digits.
+    fn gt_from_zk(gt: &bls12_381::Gt) -> Gt {
+        let debug = format!("{:?}", gt);
+        let mut coeffs = debug.split("0x").skip(1).map(|c| {
+            Fp::from_bytes_be(&hex::decode(&c[..96]).unwrap().try_into().unwrap()).unwrap()
+        });
+
+        let mut out = blst_fp12::default();
+        for fp6 in out.fp6.iter_mut() {
+            for fp2 in fp6.fp2.iter_mut() {
+                for fp in fp2.fp.iter_mut() {
+                    *fp = coeffs.next().unwrap().0;
+                }
+            }
+        }
+        assert!(coeffs.next().is_none());
+        Gt::try_from(Fp12(out)).unwrap()
+    }
+
+    #[test]
+    fn test_generators() {
+        assert!(same_layout());
+
+        assert_eq!(
+            bls12_381::Scalar::from(Scalar::from(7u64)),
+            bls12_381::Scalar::from(7u64)
+        );
+        assert_eq!(Scalar::from(-bls12_381::Scalar::one()), -Scalar::from(1u64));
+
+        assert_eq!(
+            bls12_381::G1Affine::from(G1Affine::generator()),
+            bls12_381::G1Affine::generator()
+        );
+        assert_eq!(
+            G1Affine::from(bls12_381::G1Affine::generator()),
+            G1Affine::generator()
+        );
+        assert_eq!(
+            bls12_381::G1Projective::from(G1Projective::generator()),
+            bls12_381::G1Projective::generator()
+        );
+        assert_eq!(
+            G1Projective::from(bls12_381::G1Projective::generator()),
+            G1Projective::generator()
+        );
+
+        assert_eq!(
+            bls12_381::G2Affine::from(G2Affine::generator()),
+            bls12_381::G2Affine::generator()
+        );
+        assert_eq!(
+            G2Affine::from(bls12_381::G2Affine::generator()),
+            G2Affine::generator()
+        );
+        assert_eq!(
+            bls12_381::G2Projective::from(G2Projective::generator()),
+            bls12_381::G2Projective::generator()
+        );
+        assert_eq!(
+            G2Projective::from(bls12_381::G2Projective::generator()),
+            G2Projective::generator()
+        );
+    }
+
+    #[test]
+    fn test_identities() {
+        assert_eq!(
+            bls12_381::G1Affine::from(G1Affine::identity()),
+            bls12_381::G1Affine::identity()
+        );
+        assert_eq!(
+            G1Affine::from(bls12_381::G1Affine::identity()),
+            G1Affine::identity()
+        );
+        assert!(bool::from(
+            bls12_381::G1Projective::from(G1Projective::identity()).is_identity()
+        ));
+        assert!(bool::from(
+            G1Projective::from(bls12_381::G1Projective::identity()).is_identity()
+        ));
+
+        assert_eq!(
+            bls12_381::G2Affine::from(G2Affine::identity()),
+            bls12_381::G2Affine::identity()
+        );
+        assert_eq!(
+            G2Affine::from(bls12_381::G2Affine::identity()),
+            G2Affine::identity()
+        );
+        assert!(bool::from(
+            bls12_381::G2Projective::from(G2Projective::identity()).is_identity()
+        ));
+        assert!(bool::from(
+            G2Projective::from(bls12_381::G2Projective::identity()).is_identity()
+        ));
+    }
+
+    #[test]
+    fn test_scalar_differential() {
+        let mut rng = test_rng();
+        for _ in 0..100 {
+            let a = Scalar::random(&mut rng);
+            let b = Scalar::random(&mut rng);
+            let (a_zk, b_zk) = (bls12_381::Scalar::from(a), bls12_381::Scalar::from(b));
+
+            assert_eq!(Scalar::from(a_zk), a);
+            assert_eq!(bls12_381::Scalar::from(a + b), a_zk + b_zk);
+            assert_eq!(bls12_381::Scalar::from(a - b), a_zk - b_zk);
+            assert_eq!(bls12_381::Scalar::from(a * b), a_zk * b_zk);
+            assert_eq!(bls12_381::Scalar::from(a.square()), a_zk.square());
+            assert_eq!(
+                bls12_381::Scalar::from(a.invert().unwrap()),
+                a_zk.invert().unwrap()
+            );
+            assert_eq!(a.to_bytes_le(), a_zk.to_bytes());
+
+            let c_zk = bls12_381::Scalar::random(&mut rng);
+            assert_eq!(bls12_381::Scalar::from(Scalar::from(c_zk)), c_zk);
+        }
+    }
+
+    #[test]
+    fn test_g1_differential() {
+        let mut rng = test_rng();
+        for _ in 0..50 {
+            let p = G1Projective::random(&mut rng);
+            let q = G1Projective::random(&mut rng);
+            let s = Scalar::random(&mut rng);
+            let (p_zk, q_zk) = (
+                bls12_381::G1Projective::from(p),
+                bls12_381::G1Projective::from(q),
+            );
+            let s_zk = bls12_381::Scalar::from(s);
+
+            assert_eq!(G1Projective::from(p_zk), p);
+            assert_eq!(bls12_381::G1Projective::from(p + q), p_zk + q_zk);
+            assert_eq!(bls12_381::G1Projective::from(p - q), p_zk - q_zk);
+            assert_eq!(bls12_381::G1Projective::from(p.double()), p_zk.double());
+            assert_eq!(bls12_381::G1Projective::from(p * s), p_zk * s_zk);
+            assert_eq!(G1Projective::from(p_zk * s_zk), p * s);
+
+            let (p_affine, p_zk_affine) = (p.to_affine(), bls12_381::G1Affine::from(p_zk));
+            assert_eq!(bls12_381::G1Affine::from(p_affine), p_zk_affine);
+            assert_eq!(G1Affine::from(p_zk_affine), p_affine);
+            assert_eq!(p_affine.to_compressed(), p_zk_affine.to_compressed());
+            assert_eq!(p_affine.to_uncompressed(), p_zk_affine.to_uncompressed());
+            assert_eq!(
+                G1Affine::from_compressed(&p_zk_affine.to_compressed()).unwrap(),
+                p_affine
+            );
+            assert_eq!(
+                bls12_381::G1Affine::from_compressed(&p_affine.to_compressed()).unwrap(),
+                p_zk_affine
+            );
+        }
+    }
+
+    #[test]
+    fn test_g2_differential() {
+        let mut rng = test_rng();
+        for _ in 0..50 {
+            let p = G2Projective::random(&mut rng);
+            let q = G2Projective::random(&mut rng);
+            let s = Scalar::random(&mut rng);
+            let (p_zk, q_zk) = (
+                bls12_381::G2Projective::from(p),
+                bls12_381::G2Projective::from(q),
+            );
+            let s_zk = bls12_381::Scalar::from(s);
+
+            assert_eq!(G2Projective::from(p_zk), p);
+            assert_eq!(bls12_381::G2Projective::from(p + q), p_zk + q_zk);
+            assert_eq!(bls12_381::G2Projective::from(p - q), p_zk - q_zk);
+            assert_eq!(bls12_381::G2Projective::from(p.double()), p_zk.double());
+            assert_eq!(bls12_381::G2Projective::from(p * s), p_zk * s_zk);
+            assert_eq!(G2Projective::from(p_zk * s_zk), p * s);
+
+            let (p_affine, p_zk_affine) = (p.to_affine(), bls12_381::G2Affine::from(p_zk));
+            assert_eq!(bls12_381::G2Affine::from(p_affine), p_zk_affine);
+            assert_eq!(G2Affine::from(p_zk_affine), p_affine);
+            assert_eq!(p_affine.to_compressed(), p_zk_affine.to_compressed());
+            assert_eq!(p_affine.to_uncompressed(), p_zk_affine.to_uncompressed());
+            assert_eq!(
+                G2Affine::from_compressed(&p_zk_affine.to_compressed()).unwrap(),
+                p_affine
+            );
+            assert_eq!(
+                bls12_381::G2Affine::from_compressed(&p_affine.to_compressed()).unwrap(),
+                p_zk_affine
+            );
+        }
+    }
+
+    #[test]
+    fn test_pairing_differential() {
+        let mut rng = test_rng();
+        for _ in 0..10 {
+            let p = G1Projective::random(&mut rng).to_affine();
+            let q = G2Projective::random(&mut rng).to_affine();
+            let s = Scalar::random(&mut rng);
+            let (p_zk, q_zk) = (bls12_381::G1Affine::from(p), bls12_381::G2Affine::from(q));
+
+            let gt = crate::pairing(&p, &q);
+            let gt_zk = bls12_381::pairing(&p_zk, &q_zk);
+            assert_eq!(gt_from_zk(&gt_zk), gt);
+            assert_eq!(gt_from_zk(&bls12_381::Gt::generator()), Gt::generator());
+
+            let s_zk = bls12_381::Scalar::from(s);
+            assert_eq!(gt_from_zk(&(gt_zk * s_zk)), gt * s);
+            assert_eq!(
+                gt_from_zk(&bls12_381::pairing(
+                    &bls12_381::G1Affine::from(p_zk * s_zk),
+                    &q_zk
+                )),
+                crate::pairing(&(p * s).to_affine(), &q)
+            );
+        }
+    }
+
+    #[test]
+    fn test_g2_prepared_differential() {
+        let mut rng = test_rng();
+        for _ in 0..10 {
+            let p = [
+                G1Projective::random(&mut rng).to_affine(),
+                G1Projective::random(&mut rng).to_affine(),
+            ];
+            let q = [
+                G2Projective::random(&mut rng).to_affine(),
+                G2Projective::random(&mut rng).to_affine(),
+            ];
+            let p_zk = [
+                bls12_381::G1Affine::from(p[0]),
+                bls12_381::G1Affine::from(p[1]),
+            ];
+            let q_zk = [
+                bls12_381::G2Prepared::from(bls12_381::G2Affine::from(q[0])),
+                bls12_381::G2Prepared::from(bls12_381::G2Affine::from(q[1])),
+            ];
+            let q = [G2Prepared::from(q[0]), G2Prepared::from(q[1])];
+
+            let gt =
+                Bls12::multi_miller_loop(&[(&p[0], &q[0]), (&p[1], &q[1])]).final_exponentiation();
+            let gt_zk = bls12_381::multi_miller_loop(&[(&p_zk[0], &q_zk[0]), (&p_zk[1], &q_zk[1])])
+                .final_exponentiation();
+            assert_eq!(gt_from_zk(&gt_zk), gt);
+        }
+    }
+}
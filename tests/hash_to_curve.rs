@@ -0,0 +1,111 @@
+//! Conformance tests against the hash-to-curve test vectors of RFC 9380, Appendix J.9 and J.10.
+//!
+//! The vectors are kept in `tests/hash_to_curve/` in the JSON format of the
+//! [CFRG repository](https://github.com/cfrg/draft-irtf-cfrg-hash-to-curve/tree/main/poc/vectors).
+
+use std::convert::TryInto;
+
+use blstrs::{Fp, Fp2, G1Affine, G1Projective, G2Affine, G2Projective};
+use group::Curve;
+use serde_json::Value;
+
+/// Parses a field element written as `0x`-prefixed big-endian hex.
+fn fp(s: &str) -> Fp {
+    let bytes = hex::decode(s.trim_start_matches("0x")).unwrap();
+    let mut be = [0u8; 48];
+    be[48 - bytes.len()..].copy_from_slice(&bytes);
+    Fp::from_bytes_be(&be).unwrap()
+}
+
+/// Parses an element of `Fp2` written as `c0,c1`.
+fn fp2(s: &str) -> Fp2 {
+    let [c0, c1]: [&str; 2] = s.split(',').collect::<Vec<_>>().try_into().unwrap();
+    Fp2::new(fp(c0), fp(c1))
+}
+
+fn g1(point: &Value) -> G1Projective {
+    let x = fp(point["x"].as_str().unwrap());
+    let y = fp(point["y"].as_str().unwrap());
+    G1Affine::from_raw_unchecked(x, y, false).into()
+}
+
+fn g2(point: &Value) -> G2Projective {
+    let x = fp2(point["x"].as_str().unwrap());
+    let y = fp2(point["y"].as_str().unwrap());
+    G2Affine::from_raw_unchecked(x, y, false).into()
+}
+
+macro_rules! hash_to_curve_tests {
+    ($projective:ident, $field:ident, $point:ident, $ro:ident, $nu:ident, $name:literal) => {
+        #[test]
+        fn $ro() {
+            let suite: Value = serde_json::from_str(include_str!(concat!(
+                "hash_to_curve/BLS12381",
+                $name,
+                "_XMD-SHA-256_SSWU_RO_.json"
+            )))
+            .unwrap();
+            assert_eq!(suite["randomOracle"], Value::Bool(true));
+            let dst = suite["dst"].as_str().unwrap().as_bytes();
+
+            let vectors = suite["vectors"].as_array().unwrap();
+            assert!(!vectors.is_empty());
+            for vector in vectors {
+                let msg = vector["msg"].as_str().unwrap().as_bytes();
+                let p = $point(&vector["P"]);
+                assert!(bool::from(p.to_affine().is_torsion_free()));
+                assert_eq!($projective::hash_to_curve(msg, dst, &[]), p);
+
+                let u: Vec<_> = vector["u"]
+                    .as_array()
+                    .unwrap()
+                    .iter()
+                    .map(|u| $field(u.as_str().unwrap()))
+                    .collect();
+                assert_eq!(u.len(), 2);
+                let q0 = $projective::map_to_curve(&u[0]);
+                let q1 = $projective::map_to_curve(&u[1]);
+                assert_eq!(q0, $point(&vector["Q0"]));
+                assert_eq!(q1, $point(&vector["Q1"]));
+                assert_eq!((q0 + q1).clear_cofactor(), p);
+            }
+        }
+
+        #[test]
+        fn $nu() {
+            let suite: Value = serde_json::from_str(include_str!(concat!(
+                "hash_to_curve/BLS12381",
+                $name,
+                "_XMD-SHA-256_SSWU_NU_.json"
+            )))
+            .unwrap();
+            assert_eq!(suite["randomOracle"], Value::Bool(false));
+            let dst = suite["dst"].as_str().unwrap().as_bytes();
+
+            let vectors = suite["vectors"].as_array().unwrap();
+            assert!(!vectors.is_empty());
+            for vector in vectors {
+                let msg = vector["msg"].as_str().unwrap().as_bytes();
+                let p = $point(&vector["P"]);
+                assert!(bool::from(p.to_affine().is_torsion_free()));
+                assert_eq!($projective::encode_to_curve(msg, dst, &[]), p);
+
+                let u = vector["u"].as_array().unwrap();
+                assert_eq!(u.len(), 1);
+                let q = $projective::map_to_curve(&$field(u[0].as_str().unwrap()));
+                assert_eq!(q, $point(&vector["Q"]));
+                assert_eq!(q.clear_cofactor(), p);
+            }
+        }
+    };
+}
+
+hash_to_curve_tests!(G1Projective, fp, g1, test_g1_sswu_ro, test_g1_sswu_nu, "G1");
+hash_to_curve_tests!(
+    G2Projective,
+    fp2,
+    g2,
+    test_g2_sswu_ro,
+    test_g2_sswu_nu,
+    "G2"
+);